@@ -0,0 +1,107 @@
+/*! Named buffer management
+
+The active buffer is kept by the main loop as a plain `Vec<u32>`, so all of the
+single buffer commands can keep working on it directly.
+The inactive buffers are stashed in the [BufferSet] under their name,
+and exchanged with the active buffer when switching.
+ */
+
+#[cfg(test)]
+mod testing;
+
+use std::collections::BTreeMap;
+
+/// Name of the buffer that exists when the program is started
+pub const DEFAULT_BUFFER: &str = "main";
+
+/** Set of the named buffers
+
+The set does not own the active buffer, only its name.
+Every operation that may touch the active buffer therefore takes it as an argument.
+ */
+pub struct BufferSet {
+    current: String,
+    stash: BTreeMap<String, Vec<u32>>,
+}
+
+impl BufferSet {
+    pub fn new() -> Self {
+        BufferSet {
+            current: DEFAULT_BUFFER.to_string(),
+            stash: BTreeMap::new(),
+        }
+    }
+
+    /// Check whether the buffer with the name exists, including the active one
+    pub fn exists(&self, name: &str) -> bool {
+        name == self.current || self.stash.contains_key(name)
+    }
+
+    /// Create a new empty buffer, return false if the name is already used
+    pub fn create(&mut self, name: &str) -> bool {
+        if self.exists(name) {
+            return false;
+        }
+        self.stash.insert(name.to_string(), Vec::new());
+        true
+    }
+
+    /** Make the named buffer the active one
+
+    The content of `vecbuff` is stashed under the current name,
+    and replaced with the content of the named buffer.
+    Return false if there is no buffer with the name.
+     */
+    pub fn switch(&mut self, name: &str, vecbuff: &mut Vec<u32>) -> bool {
+        if name == self.current {
+            return true;
+        }
+        let Some(target) = self.stash.remove(name) else {
+            return false;
+        };
+        let previous = std::mem::replace(vecbuff, target);
+        self.stash.insert(
+            std::mem::replace(&mut self.current, name.to_string()),
+            previous,
+        );
+        true
+    }
+
+    /// Obtain the content of the named buffer, which may be the active buffer
+    pub fn get<'a>(&'a self, name: &str, vecbuff: &'a [u32]) -> Option<&'a [u32]> {
+        if name == self.current {
+            Some(vecbuff)
+        } else {
+            self.stash.get(name).map(|x| x.as_slice())
+        }
+    }
+
+    /** Copy the content of the buffer `src` to the buffer `dst`
+
+    The buffer `dst` is created if it does not exist yet, and overwritten otherwise.
+    Return false if `src` does not exist.
+     */
+    pub fn copy(&mut self, src: &str, dst: &str, vecbuff: &mut Vec<u32>) -> bool {
+        let Some(content) = self.get(src, vecbuff).map(|x| x.to_vec()) else {
+            return false;
+        };
+        if dst == self.current {
+            *vecbuff = content;
+        } else {
+            self.stash.insert(dst.to_string(), content);
+        }
+        true
+    }
+
+    /// List the name and the length of every buffer, with a flag for the active one
+    pub fn list<'a>(&'a self, vecbuff: &'a [u32]) -> Vec<(&'a str, usize, bool)> {
+        let mut lst: Vec<(&str, usize, bool)> = self
+            .stash
+            .iter()
+            .map(|(name, buf)| (name.as_str(), buf.len(), false))
+            .collect();
+        lst.push((self.current.as_str(), vecbuff.len(), true));
+        lst.sort_by(|a, b| a.0.cmp(b.0));
+        lst
+    }
+}
@@ -0,0 +1,66 @@
+/*! Testing submodule for the buffers module
+ */
+
+use super::*;
+
+#[test]
+fn create_buffer() {
+    let mut set = BufferSet::new();
+    assert!(set.exists(DEFAULT_BUFFER));
+    assert!(!set.exists("other"));
+    assert!(set.create("other"));
+    assert!(set.exists("other"));
+    assert!(!set.create("other"));
+    assert!(!set.create(DEFAULT_BUFFER));
+}
+#[test]
+fn switch_buffer() {
+    let mut set = BufferSet::new();
+    let mut vecbuff = vec![0x61, 0x62];
+    set.create("other");
+    assert!(set.switch("other", &mut vecbuff));
+    assert!(vecbuff.is_empty());
+    vecbuff.push(0x63);
+    assert!(set.switch(DEFAULT_BUFFER, &mut vecbuff));
+    assert_eq!(vecbuff, vec![0x61, 0x62]);
+    assert_eq!(set.get("other", &vecbuff), Some(&[0x63][..]));
+    assert!(set.switch(DEFAULT_BUFFER, &mut vecbuff));
+    assert_eq!(vecbuff, vec![0x61, 0x62]);
+}
+#[test]
+fn copy_buffer() {
+    let mut set = BufferSet::new();
+    let mut vecbuff = vec![0x61];
+    assert!(set.copy(DEFAULT_BUFFER, "other", &mut vecbuff));
+    assert_eq!(set.get("other", &vecbuff), Some(&[0x61][..]));
+    set.switch("other", &mut vecbuff);
+    vecbuff.push(0x62);
+    assert!(set.copy("other", DEFAULT_BUFFER, &mut vecbuff));
+    assert_eq!(set.get(DEFAULT_BUFFER, &vecbuff), Some(&[0x61, 0x62][..]));
+    // Copying onto the active buffer replaces its content
+    set.create("empty");
+    assert!(set.copy("empty", "other", &mut vecbuff));
+    assert!(vecbuff.is_empty());
+}
+#[test]
+fn missing_buffer() {
+    let mut set = BufferSet::new();
+    let mut vecbuff = vec![0x61];
+    assert!(!set.switch("missing", &mut vecbuff));
+    assert_eq!(vecbuff, vec![0x61]);
+    assert!(!set.copy("missing", DEFAULT_BUFFER, &mut vecbuff));
+    assert_eq!(vecbuff, vec![0x61]);
+    assert_eq!(set.get("missing", &vecbuff), None);
+    assert!(!set.exists("missing"));
+}
+#[test]
+fn list_buffers() {
+    let mut set = BufferSet::new();
+    let mut vecbuff = vec![0x61, 0x62];
+    set.create("alpha");
+    set.switch("alpha", &mut vecbuff);
+    assert_eq!(
+        set.list(&vecbuff),
+        vec![("alpha", 0, true), (DEFAULT_BUFFER, 2, false)]
+    );
+}
@@ -6,6 +6,8 @@ pub mod command_list;
 mod testing;
 mod validator;
 
+use crate::transfer;
+use base64::Engine;
 pub use command_list::*;
 use validator::*;
 /** Parse the user input
@@ -38,7 +40,8 @@ fn parse_cmd_selection(inp: std::str::Chars) -> Option<Commands> {
         'c' => final_check(itr, Commands::Compress),
         'd' => final_check(itr, Commands::Decompress),
         'e' => final_check(itr, Commands::Erase),
-        'v' => final_check(itr, Commands::Valid),
+        'v' => parse_valid(itr),
+        'z' => final_check(itr, Commands::Size),
         'm' => parse_modify(itr),
         'o' => parse_read(itr),
         'w' => parse_write(itr),
@@ -46,6 +49,18 @@ fn parse_cmd_selection(inp: std::str::Chars) -> Option<Commands> {
         'r' => parse_render(itr),
         'k' => parse_kill(itr),
         'i' => parse_insertion(itr),
+        's' => parse_buffer(itr),
+        'y' => parse_yank(itr),
+        'g' => parse_put(itr),
+        '=' => Some(Commands::Compare(parse_source(itr)?)),
+        '~' => Some(Commands::Diff(parse_source(itr)?)),
+        'b' => parse_bytes(itr),
+        'u' => parse_units(itr),
+        'f' => parse_mojibake(itr),
+        'a' => parse_idna(itr),
+        't' => parse_transform(itr),
+        'j' => parse_hangul(itr),
+        ':' => parse_emoji(itr),
         _ => parse_cmd_dec(inp),
     }
 }
@@ -102,18 +117,12 @@ fn parse_number_value(inp: std::str::Chars) -> Option<u32> {
         Some(rest) =>
         // In hex
         {
-            match u32::from_str_radix(rest, 16) {
-                Ok(val) => Some(val),
-                _ => None,
-            }
+            u32::from_str_radix(rest, 16).ok()
         }
         None =>
         //In dec
         {
-            match strfm.parse::<u32>() {
-                Ok(val) => Some(val),
-                Err(_) => None,
-            }
+            strfm.parse::<u32>().ok()
         }
     }
 }
@@ -122,6 +131,21 @@ fn parse_print(inp: std::str::Chars) -> Option<Commands> {
     let mut itr = inp.clone();
     match itr.next() {
         Some('x') => final_check(itr, Commands::Print(RawBase::Hex)),
+        Some('o') => final_check(itr, Commands::Print(RawBase::Oct)),
+        Some('b') => final_check(itr, Commands::Print(RawBase::Bin)),
+        Some('+') => final_check(itr, Commands::PrintNotation),
+        Some('e') => final_check(itr, Commands::PrintEncoded),
+        Some('t') => {
+            if itr.as_str().is_empty() {
+                return Some(Commands::PrintTable { page: 1 });
+            }
+            parse_rear(itr, |rest| match parse_number_value(rest.chars())? {
+                0 => None,
+                page => Some(Commands::PrintTable {
+                    page: page as usize,
+                }),
+            })
+        }
         None => final_check(itr, Commands::Print(RawBase::Dec)),
         _ => None,
     }
@@ -140,39 +164,68 @@ fn parse_modify(inp: std::str::Chars) -> Option<Commands> {
     }
 }
 fn parse_write(inp: std::str::Chars) -> Option<Commands> {
-    let (is_32, itr) = string_exact_check(inp.clone(), "32".chars());
-    if !is_32 {
-        return parse_rear(itr.clone(), |fpath| {
-            Some(Commands::Write {
-                enc: EncodingType::UTF8,
-                file: fpath.to_string(),
-            })
-        });
+    let (label, rest) = inp.as_str().split_once(' ')?;
+    let lower = label.to_lowercase();
+    let (label, mut bom) = match lower.strip_suffix("nobom") {
+        Some(lbl) => (lbl, Some(false)),
+        None => match lower.strip_suffix("bom") {
+            Some(lbl) => (lbl, Some(true)),
+            None => (lower.as_str(), None),
+        },
+    };
+    let enc = parse_file_label(label)?;
+    let mut fallback = Fallback::Error;
+    let mut rest = rest;
+    while let Some((opt, tail)) = rest.split_once(' ') {
+        match opt.to_lowercase().as_str() {
+            "strict" => fallback = Fallback::Error,
+            "subst" => fallback = Fallback::Question,
+            "ncr" => fallback = Fallback::NumericReference,
+            "bom" => bom = Some(true),
+            "nobom" => bom = Some(false),
+            _ => break,
+        };
+        rest = tail;
     }
-    let (is_le, itr) = string_exact_check(itr, "le".chars());
-    if !is_le {
-        parse_rear(itr.clone(), |fpath| {
-            Some(Commands::Write {
-                enc: EncodingType::UTF32,
-                file: fpath.to_string(),
-            })
-        })
-    } else {
-        parse_rear(itr.clone(), |fpath| {
-            Some(Commands::Write {
-                enc: EncodingType::UTF32LE,
-                file: fpath.to_string(),
-            })
-        })
+    if rest.is_empty() {
+        return None;
     }
+    Some(Commands::Write {
+        enc,
+        file: rest.to_string(),
+        fallback,
+        bom: bom.unwrap_or(default_bom(&enc)),
+    })
+}
+
+/// Whether the byte order mark is written when not stated, only the UTF-16 and UTF-32 have it
+fn default_bom(enc: &EncodingType) -> bool {
+    matches!(
+        enc,
+        EncodingType::UTF16 | EncodingType::UTF16LE | EncodingType::UTF32 | EncodingType::UTF32LE
+    )
 }
 
 fn parse_read(inp: std::str::Chars) -> Option<Commands> {
-    return parse_rear(inp.clone(), |fpath| {
-        Some(Commands::Read {
-            file: fpath.to_string(),
-        })
-    });
+    let (label, rest) = inp.as_str().split_once(' ')?;
+    let enc = parse_file_label(label)?;
+    Some(Commands::Read {
+        enc,
+        file: rest.to_string(),
+    })
+}
+
+/// Parse the encoding of the read and write command, which is the `8`, `16`, `16le`, `32`,
+/// `32le`, `7`, `7imap` suffix, or a name accepted by [parse_encoding_label], and defaulted to UTF-8
+fn parse_file_label(inp: &str) -> Option<EncodingType> {
+    if inp.is_empty() || inp == "8" {
+        return Some(EncodingType::UTF8);
+    }
+    let (enc, itr) = parse_unicode_suffix(inp.chars());
+    if enc != EncodingType::UTF8 && itr.as_str().is_empty() {
+        return Some(enc);
+    }
+    parse_encoding_label(inp)
 }
 
 fn parse_insertion(inp: std::str::Chars) -> Option<Commands> {
@@ -198,19 +251,511 @@ fn parse_insertion(inp: std::str::Chars) -> Option<Commands> {
 }
 
 fn parse_render(inp: std::str::Chars) -> Option<Commands> {
+    let mut itr = inp.clone();
+    match itr.next() {
+        Some('%') => {
+            let (query, itr) = string_exact_check(itr, "q".chars());
+            return final_check(itr, Commands::RenderPercent { query });
+        }
+        Some('6') if itr.next() == Some('4') => {
+            return Some(Commands::RenderBase64(parse_render_label(itr)?));
+        }
+        Some('m') => {
+            let q = match itr.next()?.to_ascii_lowercase() {
+                'b' => false,
+                'q' => true,
+                _ => return None,
+            };
+            let enc = parse_render_label(itr)?;
+            if enc == EncodingType::IMAPUTF7 {
+                return None;
+            }
+            return Some(Commands::RenderMime { enc, q });
+        }
+        Some('v') => return final_check(itr, Commands::RenderReveal),
+        _ => (),
+    }
+    let (enc, itr) = parse_unicode_suffix(inp);
+    final_check(itr, Commands::Render(enc))
+}
+
+/// Parse the optional encoding following a space, which is defaulted to UTF-8
+fn parse_render_label(inp: std::str::Chars) -> Option<EncodingType> {
+    let mut itr = inp;
+    match itr.next() {
+        None => Some(EncodingType::UTF8),
+        Some(' ') => parse_file_label(itr.as_str().trim()),
+        _ => None,
+    }
+}
+
+/// Parse the optional `16`, `16le`, `32`, `32le`, `7` or `7imap` suffix, which is defaulted to UTF-8
+fn parse_unicode_suffix(inp: std::str::Chars) -> (EncodingType, std::str::Chars) {
+    let (is_7, itr) = string_exact_check(inp.clone(), "7".chars());
+    if is_7 {
+        let (is_imap, itr) = string_exact_check(itr, "imap".chars());
+        return (
+            if is_imap {
+                EncodingType::IMAPUTF7
+            } else {
+                EncodingType::UTF7
+            },
+            itr,
+        );
+    }
+    let (is_16, itr) = string_exact_check(inp.clone(), "16".chars());
+    if is_16 {
+        let (is_le, itr) = string_exact_check(itr, "le".chars());
+        return (
+            if is_le {
+                EncodingType::UTF16LE
+            } else {
+                EncodingType::UTF16
+            },
+            itr,
+        );
+    }
     let (is_32, itr) = string_exact_check(inp.clone(), "32".chars());
-    if !is_32 {
-        return final_check(inp, Commands::Render(EncodingType::UTF8));
-    }
-    let (is_le, itr) = string_exact_check(itr, "le".chars());
-    final_check(
-        itr,
-        Commands::Render(if is_le {
-            EncodingType::UTF32LE
+    if is_32 {
+        let (is_le, itr) = string_exact_check(itr, "le".chars());
+        return (
+            if is_le {
+                EncodingType::UTF32LE
+            } else {
+                EncodingType::UTF32
+            },
+            itr,
+        );
+    }
+    (EncodingType::UTF8, inp)
+}
+
+fn parse_bytes(inp: std::str::Chars) -> Option<Commands> {
+    let mut itr = inp.clone();
+    match itr.next() {
+        Some('m') => return parse_rear(itr, parse_mime),
+        Some('%') => {
+            let (query, itr) = string_exact_check(itr, "q".chars());
+            return parse_rear(itr, |rest| {
+                let (label, data) = rest.split_once(' ')?;
+                Some(Commands::DecodeBytes {
+                    enc: parse_encoding_label(label)?,
+                    bytes: transfer::percent_decode(data.trim(), query)?,
+                })
+            });
+        }
+        _ => (),
+    }
+    let (is_64, itr) = string_exact_check(inp, "64".chars());
+    parse_rear(itr, |rest| {
+        let (label, data) = rest.split_once(' ')?;
+        let enc = parse_encoding_label(label)?;
+        let bytes = if is_64 {
+            base64::engine::general_purpose::STANDARD
+                .decode(data.split_whitespace().collect::<String>())
+                .ok()?
         } else {
-            EncodingType::UTF32
+            parse_hex_bytes(data)?
+        };
+        Some(Commands::DecodeBytes { enc, bytes })
+    })
+}
+
+/// Parse the header containing RFC 2047 encoded-words, rejecting the unknown charsets
+fn parse_mime(inp: &str) -> Option<Commands> {
+    let parts = transfer::parse_encoded_words(inp)
+        .into_iter()
+        .map(|segment| match segment {
+            transfer::Segment::Text(text) => Some((EncodingType::UTF8, text.into_bytes())),
+            transfer::Segment::Word { charset, bytes } => {
+                Some((parse_encoding_label(&charset)?, bytes))
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if parts.is_empty() {
+        return None;
+    }
+    Some(Commands::DecodeMime(parts))
+}
+
+fn parse_units(inp: std::str::Chars) -> Option<Commands> {
+    parse_rear(inp, |rest| {
+        let units = rest
+            .replace("\\u", " ")
+            .replace("\\U", " ")
+            .split_whitespace()
+            .map(|x| {
+                let hex = x.strip_prefix("0x").unwrap_or(x);
+                if hex.is_empty() || hex.len() > 4 {
+                    return None;
+                }
+                u16::from_str_radix(hex, 16).ok()
+            })
+            .collect::<Option<Vec<u16>>>()?;
+        if units.is_empty() {
+            return None;
+        }
+        Some(Commands::DecodeUnits(units))
+    })
+}
+
+/** Parse the name of an encoding
+
+The unicode encodings and ISO-8859-1 are matched ignoring the case and the `-` separator,
+the other names are looked up as the WHATWG label of a legacy encoding.
+ */
+fn parse_encoding_label(inp: &str) -> Option<EncodingType> {
+    match inp.to_lowercase().replace('-', "").as_str() {
+        "utf8" => Some(EncodingType::UTF8),
+        "utf16" | "utf16be" => Some(EncodingType::UTF16),
+        "utf16le" => Some(EncodingType::UTF16LE),
+        "utf32" | "utf32be" => Some(EncodingType::UTF32),
+        "utf32le" => Some(EncodingType::UTF32LE),
+        "utf7" => Some(EncodingType::UTF7),
+        "imaputf7" | "utf7imap" => Some(EncodingType::IMAPUTF7),
+        "scsu" => Some(EncodingType::SCSU),
+        "bocu1" => Some(EncodingType::BOCU1),
+        "latin1" | "iso88591" => Some(EncodingType::Latin1),
+        _ => match encoding_rs::Encoding::for_label(inp.as_bytes())? {
+            enc if enc == encoding_rs::REPLACEMENT
+                || enc == encoding_rs::UTF_8
+                || enc == encoding_rs::UTF_16BE
+                || enc == encoding_rs::UTF_16LE =>
+            {
+                None
+            }
+            enc => Some(EncodingType::Legacy(enc)),
+        },
+    }
+}
+
+fn parse_mojibake(inp: std::str::Chars) -> Option<Commands> {
+    if inp.as_str().is_empty() {
+        return Some(Commands::MojibakeDetect);
+    }
+    parse_rear(inp, |rest| {
+        let (wrong, right) = rest.trim().split_once(' ')?;
+        Some(Commands::MojibakeRepair {
+            wrong: parse_encoding_label(wrong)?,
+            right: parse_encoding_label(right.trim_start())?,
+        })
+    })
+}
+
+fn parse_transform(inp: std::str::Chars) -> Option<Commands> {
+    let mut itr = inp;
+    let case: fn(CaseLocale) -> Transform = match itr.next()?.to_ascii_lowercase() {
+        'u' => Transform::Upper,
+        'l' => Transform::Lower,
+        't' => Transform::Title,
+        'd' => return parse_transform_range(itr, Transform::StripMarks),
+        'a' => return parse_transform_range(itr, Transform::AsciiFold),
+        'w' => return parse_transform_range(itr, Transform::Fullwidth),
+        'n' => return parse_transform_range(itr, Transform::Halfwidth),
+        'k' => return parse_transform_range(itr, Transform::Katakana),
+        'h' => return parse_transform_range(itr, Transform::Hiragana),
+        's' => return parse_rear(itr, parse_style),
+        'r' => return parse_transform_range(itr, Transform::Latin),
+        _ => return None,
+    };
+    if itr.as_str().is_empty() {
+        return Some(Commands::Transform {
+            kind: case(CaseLocale::Root),
+            range: None,
+        });
+    }
+    parse_rear(itr, |rest| {
+        let rest = rest.trim();
+        let (locale, rest) = match rest.split_once(' ').unwrap_or((rest, "")) {
+            ("tr" | "az", tail) => (CaseLocale::Turkic, tail),
+            ("lt", tail) => (CaseLocale::Lithuanian, tail),
+            _ => (CaseLocale::Root, rest),
+        };
+        Some(Commands::Transform {
+            kind: case(locale),
+            range: parse_optional_range(rest)?,
+        })
+    })
+}
+
+fn parse_valid(inp: std::str::Chars) -> Option<Commands> {
+    let mut itr = inp.clone();
+    match itr.next().map(|x| x.to_ascii_lowercase()) {
+        None => Some(Commands::Valid),
+        Some('s') => {
+            let kind = match itr.next().map(|x| x.to_ascii_lowercase()) {
+                None => return Some(Commands::VariationList),
+                Some('e') => Transform::EmojiPresentation,
+                Some('t') => Transform::TextPresentation,
+                Some('d') => Transform::DefaultPresentation,
+                Some('x') => Transform::StripSelectors,
+                _ => return None,
+            };
+            parse_transform_range(itr, kind)
+        }
+        Some('b') => final_check(itr, Commands::BidiCheck),
+        Some('c') if itr.as_str().is_empty() => Some(Commands::Confusable(None)),
+        Some('c') => Some(Commands::Confusable(Some(parse_source(itr)?))),
+        _ => None,
+    }
+}
+
+fn parse_hangul(inp: std::str::Chars) -> Option<Commands> {
+    let mut itr = inp.clone();
+    match itr.next()?.to_ascii_lowercase() {
+        'r' => parse_rear(itr, |rest| {
+            let text = rest.trim();
+            (!text.is_empty()).then(|| Commands::HangulRoman(text.to_string()))
         }),
-    )
+        'd' => parse_rear(itr, |rest| {
+            Some(Commands::HangulDecompose {
+                pos: parse_number_value(rest.trim().chars())? as usize,
+            })
+        }),
+        _ => parse_rear(inp, |rest| {
+            let idx = rest
+                .split_whitespace()
+                .map(|x| Some(parse_number_value(x.chars())? as usize))
+                .collect::<Option<Vec<usize>>>()?;
+            let (lead, vowel, tail) = match idx[..] {
+                [lead, vowel] => (lead, vowel, 0),
+                [lead, vowel, tail] => (lead, vowel, tail),
+                _ => return None,
+            };
+            Some(Commands::HangulCompose { lead, vowel, tail })
+        }),
+    }
+}
+
+fn parse_emoji(inp: std::str::Chars) -> Option<Commands> {
+    let mut itr = inp;
+    match itr.next()?.to_ascii_lowercase() {
+        'v' => final_check(itr, Commands::EmojiCheck),
+        'm' => parse_rear(itr, |rest| {
+            let (base, tone) = rest.trim().split_once(' ')?;
+            let base = match parse_emoji_element(base)[..] {
+                [base] | [base, 0xFE0F] => base,
+                _ => return None,
+            };
+            Some(Commands::EmojiModifier {
+                base,
+                tone: parse_skin_tone(tone.trim())?,
+            })
+        }),
+        'z' => parse_rear(itr, |rest| {
+            let elements: Vec<Vec<u32>> =
+                rest.split_whitespace().map(parse_emoji_element).collect();
+            (elements.len() > 1).then_some(Commands::EmojiZwj(elements))
+        }),
+        'f' => parse_rear(itr, |rest| {
+            let region = rest.trim();
+            (region.len() == 2 && region.chars().all(|x| x.is_ascii_alphabetic()))
+                .then(|| Commands::EmojiFlag(region.to_ascii_uppercase()))
+        }),
+        't' => parse_rear(itr, |rest| {
+            let code = rest.trim().replace('-', "").to_ascii_lowercase();
+            let valid = (3..=6).contains(&code.len())
+                && code.chars().take(2).all(|x| x.is_ascii_alphabetic())
+                && code.chars().all(|x| x.is_ascii_alphanumeric());
+            valid.then_some(Commands::EmojiSubdivision(code))
+        }),
+        'k' => parse_rear(itr, |rest| {
+            let mut key = rest.trim().chars();
+            match (key.next()?, key.next()) {
+                (chr @ ('0'..='9' | '#' | '*'), None) => Some(Commands::EmojiKeycap(chr)),
+                _ => None,
+            }
+        }),
+        _ => None,
+    }
+}
+
+/// Parse the element of an emoji sequence, either a codepoint or the text itself
+fn parse_emoji_element(inp: &str) -> Vec<u32> {
+    match parse_number_value(inp.chars()) {
+        Some(code) => vec![code],
+        None => inp.chars().map(|x| x as u32).collect(),
+    }
+}
+
+fn parse_skin_tone(inp: &str) -> Option<SkinTone> {
+    Some(match inp.to_ascii_lowercase().as_str() {
+        "1" | "light" => SkinTone::Light,
+        "2" | "mediumlight" => SkinTone::MediumLight,
+        "3" | "medium" => SkinTone::Medium,
+        "4" | "mediumdark" => SkinTone::MediumDark,
+        "5" | "dark" => SkinTone::Dark,
+        _ => return None,
+    })
+}
+
+/// Parse the name of the style, followed by an optional range
+fn parse_style(inp: &str) -> Option<Commands> {
+    let inp = inp.trim();
+    let (name, rest) = inp.split_once(' ').unwrap_or((inp, ""));
+    let kind = match name.to_ascii_lowercase().as_str() {
+        "plain" => Transform::Plain,
+        "bold" => Transform::Styled(Style::Bold),
+        "italic" => Transform::Styled(Style::Italic),
+        "bolditalic" => Transform::Styled(Style::BoldItalic),
+        "script" => Transform::Styled(Style::Script),
+        "boldscript" => Transform::Styled(Style::BoldScript),
+        "fraktur" => Transform::Styled(Style::Fraktur),
+        "boldfraktur" => Transform::Styled(Style::BoldFraktur),
+        "double" => Transform::Styled(Style::DoubleStruck),
+        "sans" => Transform::Styled(Style::Sans),
+        "sansbold" => Transform::Styled(Style::SansBold),
+        "sansitalic" => Transform::Styled(Style::SansItalic),
+        "sansbolditalic" => Transform::Styled(Style::SansBoldItalic),
+        "mono" => Transform::Styled(Style::Monospace),
+        "circled" => Transform::Styled(Style::Circled),
+        "super" => Transform::Styled(Style::Superscript),
+        "sub" => Transform::Styled(Style::Subscript),
+        _ => return None,
+    };
+    Some(Commands::Transform {
+        kind,
+        range: parse_optional_range(rest)?,
+    })
+}
+
+/// Parse the transformation followed by an optional range
+fn parse_transform_range(inp: std::str::Chars, kind: Transform) -> Option<Commands> {
+    if inp.as_str().is_empty() {
+        return Some(Commands::Transform { kind, range: None });
+    }
+    parse_rear(inp, |rest| {
+        Some(Commands::Transform {
+            kind,
+            range: Some(parse_range(rest)?),
+        })
+    })
+}
+
+/// Parse a range following [parse_range], or nothing for the whole buffer
+fn parse_optional_range(inp: &str) -> Option<Option<(usize, usize)>> {
+    if inp.trim().is_empty() {
+        Some(None)
+    } else {
+        Some(Some(parse_range(inp)?))
+    }
+}
+
+fn parse_idna(inp: std::str::Chars) -> Option<Commands> {
+    let mut itr = inp;
+    match itr.next() {
+        None => Some(Commands::IdnaAscii),
+        Some('u') if itr.as_str().is_empty() => Some(Commands::IdnaUnicode(None)),
+        Some('u') => parse_rear(itr, |rest| {
+            let domain = rest.trim();
+            if domain.is_empty() || domain.contains(char::is_whitespace) {
+                return None;
+            }
+            Some(Commands::IdnaUnicode(Some(domain.to_string())))
+        }),
+        _ => None,
+    }
+}
+
+/// Parse the hexadecimal bytes, optionally separated by whitespace
+fn parse_hex_bytes(inp: &str) -> Option<Vec<u8>> {
+    let digits: String = inp.split_whitespace().collect();
+    if digits.is_empty() || !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|x| u8::from_str_radix(digits.get(x..x + 2)?, 16).ok())
+        .collect()
+}
+
+fn parse_buffer(inp: std::str::Chars) -> Option<Commands> {
+    let mut itr = inp.clone();
+    match itr.next() {
+        None => Some(Commands::BufferList),
+        Some(' ') => Some(Commands::BufferSwitch {
+            name: parse_name(itr.as_str())?,
+        }),
+        Some('n') => parse_rear(itr, |rest| {
+            Some(Commands::BufferNew {
+                name: parse_name(rest)?,
+            })
+        }),
+        Some('c') => parse_rear(itr, |rest| {
+            let (src, dst) = rest.trim().split_once(' ')?;
+            Some(Commands::BufferCopy {
+                src: parse_name(src)?,
+                dst: parse_name(dst)?,
+            })
+        }),
+        _ => None,
+    }
+}
+
+fn parse_yank(inp: std::str::Chars) -> Option<Commands> {
+    let (reg, itr) = parse_register(inp)?;
+    parse_rear(itr, |rest| {
+        let (start, end) = parse_range(rest)?;
+        Some(Commands::Yank { reg, start, end })
+    })
+}
+
+fn parse_put(inp: std::str::Chars) -> Option<Commands> {
+    let (reg, itr) = parse_register(inp)?;
+    if itr.as_str().is_empty() {
+        return Some(Commands::Put { reg, pos: None });
+    }
+    parse_rear(itr, |rest| {
+        Some(Commands::Put {
+            reg,
+            pos: Some(parse_number_value(rest.chars())? as usize),
+        })
+    })
+}
+
+/// Parse the second sequence in the form of ` <name>` or `o <file>`
+fn parse_source(inp: std::str::Chars) -> Option<Source> {
+    let mut itr = inp.clone();
+    match itr.next()? {
+        ' ' => Some(Source::Buffer(parse_name(itr.as_str())?)),
+        'o' => {
+            if itr.next()? != ' ' {
+                return None;
+            }
+            Some(Source::File(itr.as_str().to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Parse the optional register name, which defaulted to [DEFAULT_REGISTER]
+fn parse_register(inp: std::str::Chars) -> Option<(char, std::str::Chars)> {
+    let mut itr = inp.clone();
+    match itr.next() {
+        Some(reg) if reg.is_ascii_alphanumeric() => Some((reg, itr)),
+        Some(' ') | None => Some((DEFAULT_REGISTER, inp)),
+        _ => None,
+    }
+}
+
+/// Parse a range in the form of `<start> <end>`, where *end* must not preceed *start*
+fn parse_range(inp: &str) -> Option<(usize, usize)> {
+    let (start, end) = inp.trim().split_once(' ')?;
+    let start = parse_number_value(start.chars())? as usize;
+    let end = parse_number_value(end.trim_start().chars())? as usize;
+    if end < start {
+        return None;
+    }
+    Some((start, end))
+}
+
+/// Parse a buffer name, which is a single word without any whitespace
+fn parse_name(inp: &str) -> Option<String> {
+    let name = inp.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return None;
+    }
+    Some(name.to_string())
 }
 
 fn parse_rear<F>(inp: std::str::Chars, clos: F) -> Option<Commands>
@@ -221,5 +766,5 @@ where
     if itr.next()? != ' ' {
         return None;
     }
-    return clos(itr.as_str());
+    clos(itr.as_str())
 }
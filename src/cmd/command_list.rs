@@ -3,22 +3,157 @@
 
 /** Encoding type for the writing and rendering
  */
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
 #[allow(unused_variables)]
 #[allow(dead_code)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum EncodingType {
     /// As UTF-8
     UTF8,
+    /// As UTF-16 Big Endian
+    UTF16,
+    /// As UTF-16 Little Endian
+    UTF16LE,
     /// As UTF-32 Big Endian
     UTF32,
     /// As UTF-32 Little Endian
     UTF32LE,
+    /// As UTF-7
+    UTF7,
+    /// As IMAP modified UTF-7
+    IMAPUTF7,
+    /// As the Standard Compression Scheme for Unicode
+    SCSU,
+    /// As the Binary Ordered Compression for Unicode
+    BOCU1,
+    /// As ISO-8859-1, mapping each byte to the codepoint of the same value
+    Latin1,
+    /// As a legacy encoding, identified by its WHATWG label
+    Legacy(&'static encoding_rs::Encoding),
+}
+
+/** Language specific rules of the case mapping
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum CaseLocale {
+    /// Rules shared by all of the languages
+    Root,
+    /// Turkish and Azeri, with the dotted and dotless i
+    Turkic,
+    /// Lithuanian, keeping the dot above the i followed by an accent
+    Lithuanian,
+}
+
+/** Style of the letters and the digits
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Style {
+    Bold,
+    Italic,
+    BoldItalic,
+    Script,
+    BoldScript,
+    Fraktur,
+    BoldFraktur,
+    DoubleStruck,
+    Sans,
+    SansBold,
+    SansItalic,
+    SansBoldItalic,
+    Monospace,
+    Circled,
+    Superscript,
+    Subscript,
 }
 
+/** Skin tone of the emoji modifier sequences, following the Fitzpatrick scale
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum SkinTone {
+    /// Type 1-2
+    Light,
+    /// Type 3
+    MediumLight,
+    /// Type 4
+    Medium,
+    /// Type 5
+    MediumDark,
+    /// Type 6
+    Dark,
+}
+
+/** Transformation applied over a range of the buffer
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Transform {
+    /// Full uppercase mapping
+    Upper(CaseLocale),
+    /// Full lowercase mapping
+    Lower(CaseLocale),
+    /// Titlecase of the first letter of each word, and lowercase of the others
+    Title(CaseLocale),
+    /// Removal of the nonspacing marks after the canonical decomposition, followed by the recomposition
+    StripMarks,
+    /// Removal of the nonspacing marks, followed by the transliteration of the other letters to ASCII
+    AsciiFold,
+    /// Conversion to the fullwidth form of the ASCII, the katakana and the symbols
+    Fullwidth,
+    /// Conversion to the halfwidth form of the ASCII, the katakana and the symbols
+    Halfwidth,
+    /// Conversion of the hiragana to the katakana
+    Katakana,
+    /// Conversion of the katakana to the hiragana
+    Hiragana,
+    /// Conversion of the ASCII letters and digits to a style
+    Styled(Style),
+    /// Conversion of the styled letters and digits back to ASCII
+    Plain,
+    /// Transliteration of the Cyrillic, Greek, Arabic, Hebrew and Hangul scripts to Latin
+    Latin,
+    /// Emoji presentation selector after each character having emoji variation sequences
+    EmojiPresentation,
+    /// Text presentation selector after each character having emoji variation sequences
+    TextPresentation,
+    /// Removal of the emoji and text presentation selectors
+    DefaultPresentation,
+    /// Removal of all of the variation selectors
+    StripSelectors,
+}
+
+/** Handling of the codepoints which are not representable in the encoding of the file
+ */
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Fallback {
+    /// Refuse to write the file
+    Error,
+    /// Write `?` in place of the codepoint
+    Question,
+    /// Write the numeric character reference `&#NNNN;` in place of the codepoint
+    NumericReference,
+}
+
+/** Source of a second codepoint sequence
+
+Used by the commands that work on the active buffer together with another sequence.
+ */
+#[derive(Debug, Eq, PartialEq)]
+pub enum Source {
+    /// Named buffer
+    Buffer(String),
+    /// File read as UTF-8
+    File(String),
+}
+
+/// Number of lines in a single page of [PrintTable](Commands::PrintTable)
+pub const TABLE_PAGE: usize = 20;
+
+/// Register used by the yank and put command when no register is named
+pub const DEFAULT_REGISTER: char = '"';
+
 /** Base number for the printing command
 
-The buffer of the text can be printed in the hex form, or decimal form.
-This enum is a tag for whether to print it as a hex form or decimal.
+The buffer of the text can be printed in the hex, decimal, octal or binary form.
+This enum is a tag for the base to print it in.
  */
 #[derive(Debug, Eq, PartialEq)]
 #[allow(unused_variables)]
@@ -28,6 +163,10 @@ pub enum RawBase {
     Dec,
     /// Hexadecimal type
     Hex,
+    /// Octal type
+    Oct,
+    /// Binary type
+    Bin,
 }
 
 /**
@@ -50,26 +189,63 @@ pub enum Commands {
     /// Write the buffer to a file
     /// # Command
     /// + `.w <file>` : Write as UTF-8 to *file*.
+    /// + `.w8 <file>` : Write as UTF-8 to *file*.
+    /// + `.w16 <file>` : Write as UTF-16 Big Endian to *file*.
+    /// + `.w16LE <file>` : Write as UTF-16 Little Endian to *file*.
     /// + `.w32 <file>` : Write as UTF-32 Big Endian to *file*.
     /// + `.w32LE <file>` : Write as UTF-32 Little Endian to *file*.
+    /// + `.w7 <file>` : Write as UTF-7 to *file*.
+    /// + `.w7imap <file>` : Write as IMAP modified UTF-7 to *file*.
+    /// + `.w<enc> <file>` : Write with the encoding *enc* to *file*, e.g. `.wshift_jis <file>`.
+    ///
+    /// Where `<enc>` follows [DecodeBytes](Commands::DecodeBytes).
+    /// # Fallback
+    /// The codepoints which are not representable in the encoding are reported with their position.
+    /// The handling of those codepoints is chosen by an option preceeding the *file*.
+    /// + `strict` : Do not write the file, the default.
+    /// + `subst` : Write `?` in place of the codepoint, e.g. `.wwindows-1252 subst <file>`.
+    /// + `ncr` : Write `&#NNNN;` in place of the codepoint.
+    /// # Byte order mark
+    /// The byte order mark is written for UTF-16 and UTF-32, but not for the other encodings.
+    /// This is overridden by the `bom` or `nobom` option, either preceeding the *file*,
+    /// or directly following the encoding.
+    /// + `.w8bom <file>` : Write as UTF-8 with the byte order mark.
+    /// + `.w32le nobom <file>` : Write as UTF-32 Little Endian without the byte order mark.
+    ///
+    /// For the legacy encodings, the byte order mark is `U+FEFF` in that encoding,
+    /// and only exists in GB18030.
     /// # Note
     /// Write does not perform any processing on the filename, therefore the escaping mechanism
     /// in [AppendLit](Commands::AppendLit) is bypassed.
-    Write { enc: EncodingType, file: String },
+    Write {
+        enc: EncodingType,
+        file: String,
+        fallback: Fallback,
+        bom: bool,
+    },
     /// Open and read a file to the buffer
     /// # Command
-    /// + `.o <file>` : Read the *file* and store its content in the buffer
+    /// + `.o <file>` : Read the *file* as UTF-8 and store its content in the buffer
+    /// + `.o<enc> <file>` : Read the *file* with the encoding *enc*, which follows [Write](Commands::Write)
+    ///
+    /// The leading byte order mark of the encoding is skipped.
+    /// Each invalid or truncated sequence is stored as `U+FFFD`,
+    /// and reported together with its byte offset.
     /// # Note
     /// Read does not perform any processing nor length check on the file.
     /// Therefore the responsibility to ensure enough memory,
     /// and the validity of the file is left to the user
-    Read { file: String },
+    Read { enc: EncodingType, file: String },
     /// The help page of the program
     /// Should print all of the available command and the usage
     /// # Command
     /// + `.h`
     /// + `.?`
     Help,
+    /// Compare the size of the buffer encoded with each of the unicode encodings
+    /// # Command
+    /// `.z`
+    Size,
     /// Compress the text in the buffer to NFC representation
     /// # Command
     /// `.c`
@@ -153,7 +329,30 @@ pub enum Commands {
     /// # Command
     /// `.p` Print the codepoint as decimal
     /// `.px` Print the codepoint as hexadecimal
+    /// `.po` Print the codepoint as octal
+    /// `.pb` Print the codepoint as binary
     Print(RawBase),
+    /// Print the current buffer as a list of `U+XXXX`
+    /// # Command
+    /// `.p+`
+    PrintNotation,
+    /// Print the current buffer as a table, one codepoint per line
+    ///
+    /// Each line contains the index, the glyph, the hexadecimal codepoint, the name
+    /// and the UTF-8 bytes of the codepoint.
+    /// The table is split into pages of [TABLE_PAGE](TABLE_PAGE) lines.
+    /// # Command
+    /// + `.pt` : Print the first page
+    /// + `.pt <page>` : Print the page number *page*, starting from 1
+    PrintTable { page: usize },
+    /// Print the encoded bytes of every codepoint in the current buffer
+    ///
+    /// Each line contains the UTF-8 bytes, the UTF-8 bit pattern with the marker bits
+    /// of the lead and continuation bytes separated from the payload,
+    /// the UTF-16 Big Endian bytes, and the UTF-32 Big Endian bytes of the codepoint.
+    /// # Command
+    /// `.pe`
+    PrintEncoded,
     /// Purge the buffer to empty it
     /// # Command
     /// `.e`
@@ -163,11 +362,306 @@ pub enum Commands {
     /// defaulted to UTF-8
     /// # Command
     /// + `.r` : Render to stdout as UTF-8
+    /// + `.r16` : Render to stdout as UTF-16 Big Endian
+    /// + `.r16LE` : Render to stdout as UTF-16 Little Endian
     /// + `.r32` : Render to stdout as UTF-32 Big Endian
     /// + `.r32LE` : Render to stdout as UTF-32 Little Endian
+    /// + `.r7` : Render to stdout the UTF-7 encoded text
+    /// + `.r7imap` : Render to stdout the IMAP modified UTF-7 encoded text
     Render(EncodingType),
+    /// Render the buffer encoded in UTF-8 with percent-encoding
+    /// # Command
+    /// + `.r%` : Render for a URL path, keeping the `/`
+    /// + `.r%q` : Render for a URL query, writing the space as `+`
+    RenderPercent { query: bool },
+    /// Render the buffer encoded in base64
+    /// # Command
+    /// + `.r64` : Render the UTF-8 bytes
+    /// + `.r64 <enc>` : Render the bytes of the encoding *enc*, e.g. `.r64 16le`
+    ///
+    /// Where `<enc>` follows [Write](Commands::Write).
+    RenderBase64(EncodingType),
+    /// Render the buffer as RFC 2047 encoded-words, for the header of an email
+    /// # Command
+    /// + `.rmb [enc]` : Render with the `B` encoding
+    /// + `.rmq [enc]` : Render with the `Q` encoding
+    ///
+    /// Where the optional `<enc>` follows [Write](Commands::Write), and is defaulted to UTF-8.
+    /// # Note
+    /// The words are split to stay within 75 characters, and folded on separate lines.
+    RenderMime { enc: EncodingType, q: bool },
+    /// Render the buffer with the invisible characters revealed
+    ///
+    /// The default ignorable codepoints, e.g. the zero width joiner, the BOM and the soft hyphen,
+    /// the bidirectional controls and the control characters other than the newline and the tab
+    /// are written as a placeholder, e.g. `⟨ZWJ⟩`, `⟨RLO⟩` or `⟨U+115F⟩`.
+    /// # Command
+    /// `.rv`
+    RenderReveal,
     /// Validate the current buffer
     /// # Command
     /// `.v`
     Valid,
+    /// List all of the named buffers, and their length
+    /// # Command
+    /// `.s`
+    BufferList,
+    /// Switch the active buffer to another named buffer
+    /// # Command
+    /// + `.s <name>`
+    /// # Note
+    /// The buffer must be created beforehand with [BufferNew](Commands::BufferNew).
+    /// The buffer available at the start of the program is named `main`.
+    BufferSwitch { name: String },
+    /// Create a new empty named buffer
+    /// # Command
+    /// + `.sn <name>`
+    BufferNew { name: String },
+    /// Copy the whole content of a buffer to another buffer
+    /// # Command
+    /// + `.sc <src> <dst>`
+    /// # Note
+    /// The buffer *dst* is created when it does not exist, and overwritten otherwise.
+    BufferCopy { src: String, dst: String },
+    /// Yank a range of codepoints from the active buffer to a register
+    /// # Command
+    /// + `.y <start> <end>` : Yank to the default register
+    /// + `.yr <start> <end>` : Yank to the register *r*
+    ///
+    /// The range starts at *start* and stops before *end*,
+    /// both of them following the number format of [Kill](Commands::Kill).
+    /// A register is named by a single alphanumeric character.
+    /// # Note
+    /// Registers are shared between all of the buffers,
+    /// therefore they can be used to copy part of a buffer to another buffer.
+    Yank { reg: char, start: usize, end: usize },
+    /// Put the content of a register to the active buffer
+    /// # Command
+    /// + `.g` : Append the default register
+    /// + `.gr` : Append the register *r*
+    /// + `.gr <pos>` : Insert the register *r* at *pos*
+    ///
+    /// The register naming follows [Yank](Commands::Yank).
+    Put { reg: char, pos: Option<usize> },
+    /// Compare the active buffer against another sequence
+    ///
+    /// Reports the binary equality, the equivalence under each normalization form,
+    /// the canonical caseless equivalence, and the first differing position.
+    /// # Command
+    /// + `.= <name>` : Compare against the buffer *name*
+    /// + `.=o <file>` : Compare against the content of *file*
+    Compare(Source),
+    /// Show the codepoint difference from the active buffer to another sequence
+    ///
+    /// Each codepoint is listed with its position in both sequences, marked as
+    /// kept (` `), inserted (`+`), deleted (`-`) or changed (`~`).
+    /// # Command
+    /// + `.~ <name>` : Difference against the buffer *name*
+    /// + `.~o <file>` : Difference against the content of *file*
+    Diff(Source),
+    /// Decode the bytes with an encoding, and append the result to the buffer
+    /// # Command
+    /// + `.b <enc> <hex>` : Decode the bytes written in hexadecimal, e.g. `.b utf8 E2 82 AC`
+    /// + `.b64 <enc> <base64>` : Decode the bytes written in base64
+    /// + `.b% <enc> <text>` : Decode the percent-encoded bytes, e.g. `.b% utf8 caf%C3%A9`
+    /// + `.b%q <enc> <text>` : Same as above, with `+` read as a space
+    ///
+    /// Where `<enc>` is one of `utf8`, `utf16`, `utf16le`, `utf32`, `utf32le`, `utf7`, `imaputf7`,
+    /// `scsu`, `bocu1`, `latin1`, or a WHATWG label of a legacy encoding such as `windows-1252`.
+    /// # Note
+    /// Each invalid or truncated sequence is appended as `U+FFFD`,
+    /// and reported together with its byte offset.
+    DecodeBytes { enc: EncodingType, bytes: Vec<u8> },
+    /// Append the header containing RFC 2047 encoded-words to the buffer
+    /// # Command
+    /// + `.bm <text>`, e.g. `.bm =?UTF-8?Q?caf=C3=A9?=`
+    ///
+    /// The text outside of the encoded-words is appended as is,
+    /// and the whitespace between two encoded-words is dropped.
+    /// # Note
+    /// Each invalid or truncated sequence is appended as `U+FFFD`,
+    /// and reported together with its byte offset in the word.
+    DecodeMime(Vec<(EncodingType, Vec<u8>)>),
+    /// Append the UTF-16 code units to the buffer, joining the surrogate pairs
+    /// # Command
+    /// + `.u <units>`
+    ///
+    /// Where `<units>` is a list of hexadecimal code units, separated by whitespace
+    /// or written as `\uXXXX` escapes, e.g. `.u D83D DE00` or `.u \uD83D\uDE00`.
+    /// # Note
+    /// Each unpaired surrogate is appended as `U+FFFD`, and reported together with its index.
+    DecodeUnits(Vec<u16>),
+    /// Detect the text which was encoded with an encoding, but decoded with another encoding
+    ///
+    /// Every candidate pair which can be reversed is listed, with a preview of the repaired buffer.
+    /// # Command
+    /// `.f`
+    MojibakeDetect,
+    /// Reverse the wrong decoding of the buffer
+    ///
+    /// The buffer is encoded back with *wrong*, and the bytes are decoded with *right*.
+    /// The result is previewed, and the buffer is only replaced after a confirmation.
+    /// # Command
+    /// + `.f <wrong> <right>`, e.g. `.f windows-1252 utf8` to repair `cafÃ©`
+    ///
+    /// The encoding names follow [DecodeBytes](Commands::DecodeBytes).
+    MojibakeRepair {
+        wrong: EncodingType,
+        right: EncodingType,
+    },
+    /// Transform the buffer or a range of the buffer
+    /// # Command
+    /// + `.tu [locale] [<start> <end>]` : Convert to uppercase, e.g. `ß` to `SS`
+    /// + `.tl [locale] [<start> <end>]` : Convert to lowercase
+    /// + `.tt [locale] [<start> <end>]` : Convert to titlecase, e.g. `ǆemal` to `ǅemal`
+    /// + `.td [<start> <end>]` : Strip the diacritics, e.g. `Crème` to `Creme`
+    /// + `.ta [<start> <end>]` : Strip the diacritics and fold to ASCII, e.g. `Łódź Æsir` to `Lodz AEsir`
+    /// + `.tw [<start> <end>]` : Convert to the fullwidth form, e.g. `ｶﾞA` to `ガＡ`
+    /// + `.tn [<start> <end>]` : Convert to the halfwidth form, e.g. `ガＡ` to `ｶﾞA`
+    /// + `.tk [<start> <end>]` : Convert the hiragana to the katakana
+    /// + `.th [<start> <end>]` : Convert the katakana to the hiragana
+    /// + `.ts <style> [<start> <end>]` : Convert the ASCII letters and digits to *style*,
+    ///   e.g. `.ts bold` to convert `bold` to `𝐛𝐨𝐥𝐝`
+    /// + `.tr [<start> <end>]` : Transliterate to Latin, e.g. `Москва` to `Moskva`
+    /// + `.vse [<start> <end>]` : Add or replace the emoji presentation selector VS16, e.g. `❤` to `❤️`
+    /// + `.vst [<start> <end>]` : Add or replace the text presentation selector VS15
+    /// + `.vsd [<start> <end>]` : Remove VS15 and VS16, going back to the default presentation
+    /// + `.vsx [<start> <end>]` : Strip all of the variation selectors
+    ///
+    /// Where the optional `<locale>` is one of `tr` or `az` for the Turkish and Azeri rules,
+    /// or `lt` for the Lithuanian rules.
+    ///
+    /// Where `<style>` is one of `bold`, `italic`, `bolditalic`, `script`, `boldscript`,
+    /// `fraktur`, `boldfraktur`, `double`, `sans`, `sansbold`, `sansitalic`, `sansbolditalic`,
+    /// `mono`, `circled`, `super`, `sub`, or `plain` to convert the styled characters back to ASCII.
+    /// The range follows [Yank](Commands::Yank), and is defaulted to the whole buffer.
+    /// # Note
+    /// The case mappings are the full mappings, therefore the length of the buffer may change.
+    ///
+    /// The codepoints which could not be folded to ASCII, or which have no form in the style,
+    /// are left unchanged and reported.
+    /// The codepoints of the scripts without transliteration are left unchanged.
+    ///
+    /// The presentation selector is neither added after the emoji followed by a skin tone
+    /// modifier, nor after the digits, `#` and `*` out of the keycaps.
+    Transform {
+        kind: Transform,
+        range: Option<(usize, usize)>,
+    },
+    /// Convert the buffer to an ASCII domain name
+    ///
+    /// The labels are mapped following UTS #46 and checked against the bidi rule
+    /// and the contextual rules of RFC 5892, before being encoded in Punycode with the `xn--` prefix.
+    /// The buffer is replaced by the ASCII domain.
+    /// # Command
+    /// `.a`
+    IdnaAscii,
+    /// Convert an ASCII domain name back to the codepoints
+    ///
+    /// The labels with the `xn--` prefix are decoded from Punycode, and the same checks as
+    /// [IdnaAscii](Commands::IdnaAscii) are applied.
+    /// The buffer is replaced by the decoded domain.
+    /// # Command
+    /// + `.au` to decode the content of the buffer
+    /// + `.au <domain>`, e.g. `.au xn--bcher-kva`
+    IdnaUnicode(Option<String>),
+    /// Append the Hangul syllable composed from the index of its jamo
+    ///
+    /// The arithmetic leading to the syllable is printed.
+    /// # Command
+    /// + `.j <lead> <vowel> [<tail>]`, e.g. `.j 18 0 4` to append `한`
+    ///
+    /// Where the lead is below 19, the vowel below 21 and the tail below 28,
+    /// each following the number format of [Kill](Commands::Kill).
+    /// The tail is defaulted to 0, which stands for no final consonant.
+    HangulCompose {
+        lead: usize,
+        vowel: usize,
+        tail: usize,
+    },
+    /// Append the Hangul syllables read from the Revised Romanization
+    /// # Command
+    /// + `.jr <text>`, e.g. `.jr han-geul` to append `한글`
+    ///
+    /// The syllables may be separated by `-` or `.` when the split is ambiguous.
+    HangulRoman(String),
+    /// Decompose the Hangul syllable in position into its conjoining jamo
+    ///
+    /// The arithmetic leading to the syllable and the jamo are printed.
+    /// # Command
+    /// + `.jd <pos>`
+    ///
+    /// Where `<pos>` follows the number format of [Kill](Commands::Kill).
+    HangulDecompose { pos: usize },
+    /// List the characters of the buffer which have variation sequences
+    ///
+    /// Each character is listed together with its variation selectors and the variant they select,
+    /// and the selector following it in the buffer, if any.
+    /// The digits, `#` and `*` are only listed within a keycap or when followed by a selector,
+    /// except for the zero which has a standardized variant.
+    /// # Command
+    /// `.vs`
+    VariationList,
+    /// Check the buffer for the confusable characters following UTS #39
+    ///
+    /// Without a source, the skeleton of the buffer is printed together with the characters
+    /// mapped to build it, the restriction level, the scripts of the buffer,
+    /// and the whole-script or mixed-script confusable strings.
+    /// With a source, the skeletons of both sequences are compared.
+    /// # Command
+    /// + `.vc` : Check the active buffer
+    /// + `.vc <name>` : Compare against the buffer *name*
+    /// + `.vco <file>` : Compare against the content of *file*
+    /// # Note
+    /// The first look for the whole-script and mixed-script confusables goes through every codepoint,
+    /// so it may take a moment.
+    Confusable(Option<Source>),
+    /// Check the bidirectional controls of the buffer, as the Trojan Source detectors do
+    ///
+    /// Each embedding, override and isolate must be closed by its PDF or PDI within the same line,
+    /// and each PDF and PDI must close an opening control.
+    /// The unbalanced controls are listed with their position.
+    /// # Command
+    /// `.vb`
+    BidiCheck,
+    /// Append the emoji followed by a skin tone modifier
+    /// # Command
+    /// + `.:m <emoji> <tone>`, e.g. `.:m 👍 medium` or `.:m x1F44D 3`
+    ///
+    /// Where `<emoji>` is either the codepoint following the number format of [Kill](Commands::Kill),
+    /// or the emoji itself, and `<tone>` is one of `light`, `mediumlight`, `medium`, `mediumdark`
+    /// and `dark`, or its number from 1 to 5.
+    EmojiModifier { base: u32, tone: SkinTone },
+    /// Append the elements joined by the zero width joiner
+    /// # Command
+    /// + `.:z <element> <element>...`, e.g. `.:z 👩 💻` or `.:z x1F469 x1F4BB`
+    ///
+    /// Where each element is either a codepoint following the number format of [Kill](Commands::Kill),
+    /// or the text of the element, e.g. `🏳️` together with its presentation selector.
+    EmojiZwj(Vec<Vec<u32>>),
+    /// Append the flag of the region as a pair of regional indicators
+    /// # Command
+    /// + `.:f <region>`, e.g. `.:f jp`
+    ///
+    /// Where `<region>` is the ISO 3166-1 alpha-2 code of the region.
+    EmojiFlag(String),
+    /// Append the flag of the subdivision as a tag sequence
+    /// # Command
+    /// + `.:t <subdivision>`, e.g. `.:t gb-sct`
+    ///
+    /// Where `<subdivision>` is the ISO 3166-2 code of the subdivision, with or without the hyphen.
+    EmojiSubdivision(String),
+    /// Append the keycap of the key
+    /// # Command
+    /// + `.:k <key>`, e.g. `.:k #`
+    ///
+    /// Where `<key>` is a digit, `#` or `*`.
+    EmojiKeycap(char),
+    /// List the emoji sequences of the buffer, and check them against the RGI emoji set
+    /// # Command
+    /// `.:v`
+    /// # Note
+    /// Each of the building commands prints whether the appended sequence is in the RGI set,
+    /// the sequence is appended in both cases.
+    EmojiCheck,
 }
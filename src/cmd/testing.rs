@@ -37,6 +37,11 @@ fn parse_erase() {
     assert_eq!(capture(".euuu"), None);
 }
 #[test]
+fn parse_size() {
+    assert_eq!(capture(".z"), Some(Commands::Size));
+    assert_eq!(capture(".z8"), None);
+}
+#[test]
 fn parse_valid() {
     assert_eq!(capture(".v"), Some(Commands::Valid));
     assert_eq!(capture(".vuuu"), None);
@@ -56,6 +61,16 @@ fn parse_kill() {
 fn parse_print() {
     assert_eq!(capture(".p"), Some(Commands::Print(RawBase::Dec)));
     assert_eq!(capture(".px"), Some(Commands::Print(RawBase::Hex)));
+    assert_eq!(capture(".po"), Some(Commands::Print(RawBase::Oct)));
+    assert_eq!(capture(".pb"), Some(Commands::Print(RawBase::Bin)));
+    assert_eq!(capture(".p+"), Some(Commands::PrintNotation));
+    assert_eq!(capture(".pt"), Some(Commands::PrintTable { page: 1 }));
+    assert_eq!(capture(".pt 3"), Some(Commands::PrintTable { page: 3 }));
+    assert_eq!(capture(".pt 0"), None);
+    assert_eq!(capture(".pt3"), None);
+    assert_eq!(capture(".p+x"), None);
+    assert_eq!(capture(".pe"), Some(Commands::PrintEncoded));
+    assert_eq!(capture(".pe8"), None);
     assert_eq!(capture(".pu"), None);
     assert_eq!(capture(".pxaha"), None);
     assert_eq!(capture(".px no"), None);
@@ -143,6 +158,17 @@ fn parse_render() {
         capture(".r32lE"),
         Some(Commands::Render(EncodingType::UTF32LE))
     );
+    assert_eq!(capture(".r16"), Some(Commands::Render(EncodingType::UTF16)));
+    assert_eq!(
+        capture(".r16le"),
+        Some(Commands::Render(EncodingType::UTF16LE))
+    );
+    assert_eq!(capture(".r7"), Some(Commands::Render(EncodingType::UTF7)));
+    assert_eq!(
+        capture(".r7IMAP"),
+        Some(Commands::Render(EncodingType::IMAPUTF7))
+    );
+    assert_eq!(capture(".r7le"), None);
     assert_eq!(capture(".ra"), None);
     assert_eq!(capture(".r32uu"), None);
     assert_eq!(capture(".r a"), None);
@@ -158,6 +184,8 @@ fn parse_write() {
         Some(Commands::Write {
             enc: EncodingType::UTF8,
             file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: false,
         })
     );
     assert_eq!(
@@ -165,6 +193,8 @@ fn parse_write() {
         Some(Commands::Write {
             enc: EncodingType::UTF32,
             file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: true,
         })
     );
     assert_eq!(
@@ -172,6 +202,8 @@ fn parse_write() {
         Some(Commands::Write {
             enc: EncodingType::UTF32LE,
             file: "./nya".to_string(),
+            fallback: Fallback::Error,
+            bom: true,
         })
     );
     assert_eq!(
@@ -179,8 +211,94 @@ fn parse_write() {
         Some(Commands::Write {
             enc: EncodingType::UTF32LE,
             file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: true,
+        })
+    );
+    assert_eq!(
+        capture(".w16 ./nyaaa"),
+        Some(Commands::Write {
+            enc: EncodingType::UTF16,
+            file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: true,
+        })
+    );
+    assert_eq!(
+        capture(".w16le ./nyaaa"),
+        Some(Commands::Write {
+            enc: EncodingType::UTF16LE,
+            file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: true,
+        })
+    );
+    assert_eq!(
+        capture(".wwindows-1252 ./nyaaa"),
+        Some(Commands::Write {
+            enc: EncodingType::Legacy(encoding_rs::WINDOWS_1252),
+            file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: false,
+        })
+    );
+    assert_eq!(
+        capture(".wshift_jis ncr ./nyaaa"),
+        Some(Commands::Write {
+            enc: EncodingType::Legacy(encoding_rs::SHIFT_JIS),
+            file: "./nyaaa".to_string(),
+            fallback: Fallback::NumericReference,
+            bom: false,
         })
     );
+    assert_eq!(
+        capture(".wlatin1 subst ./nya aa"),
+        Some(Commands::Write {
+            enc: EncodingType::Latin1,
+            file: "./nya aa".to_string(),
+            fallback: Fallback::Question,
+            bom: false,
+        })
+    );
+    assert_eq!(
+        capture(".w8bom ./nyaaa"),
+        Some(Commands::Write {
+            enc: EncodingType::UTF8,
+            file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: true,
+        })
+    );
+    assert_eq!(
+        capture(".w32le nobom ./nyaaa"),
+        Some(Commands::Write {
+            enc: EncodingType::UTF32LE,
+            file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: false,
+        })
+    );
+    assert_eq!(
+        capture(".w16NoBom ./nyaaa"),
+        Some(Commands::Write {
+            enc: EncodingType::UTF16,
+            file: "./nyaaa".to_string(),
+            fallback: Fallback::Error,
+            bom: false,
+        })
+    );
+    assert_eq!(
+        capture(".wgb18030 bom subst ./nyaaa"),
+        Some(Commands::Write {
+            enc: EncodingType::Legacy(encoding_rs::GB18030),
+            file: "./nyaaa".to_string(),
+            fallback: Fallback::Question,
+            bom: true,
+        })
+    );
+    assert_eq!(capture(".wlatin1"), None);
+    assert_eq!(capture(".wbomb ./nyaaa"), None);
+    assert_eq!(capture(".wnothing ./nyaaa"), None);
     assert_eq!(capture(".w32BE ./nyaaa"), None);
     assert_eq!(capture(".w32./nyaaa"), None);
 }
@@ -198,3 +316,579 @@ fn parse_modify() {
     assert_eq!(capture(".m30.20"), None);
     assert_eq!(capture(".m2--99"), None);
 }
+
+#[test]
+fn parse_buffer() {
+    assert_eq!(capture(".s"), Some(Commands::BufferList));
+    assert_eq!(
+        capture(".s nfd"),
+        Some(Commands::BufferSwitch {
+            name: "nfd".to_string()
+        })
+    );
+    assert_eq!(
+        capture(".sn nfd"),
+        Some(Commands::BufferNew {
+            name: "nfd".to_string()
+        })
+    );
+    assert_eq!(
+        capture(".sc main nfd"),
+        Some(Commands::BufferCopy {
+            src: "main".to_string(),
+            dst: "nfd".to_string()
+        })
+    );
+    assert_eq!(capture(".snnfd"), None);
+    assert_eq!(capture(".s two words"), None);
+    assert_eq!(capture(".sc main"), None);
+    assert_eq!(capture(".sc a b c"), None);
+}
+
+#[test]
+fn parse_register() {
+    assert_eq!(
+        capture(".y 2 5"),
+        Some(Commands::Yank {
+            reg: DEFAULT_REGISTER,
+            start: 2,
+            end: 5
+        })
+    );
+    assert_eq!(
+        capture(".ya 0 x10"),
+        Some(Commands::Yank {
+            reg: 'a',
+            start: 0,
+            end: 0x10
+        })
+    );
+    assert_eq!(capture(".ya 5 2"), None);
+    assert_eq!(capture(".ya 5"), None);
+    assert_eq!(capture(".yab 0 1"), None);
+    assert_eq!(
+        capture(".g"),
+        Some(Commands::Put {
+            reg: DEFAULT_REGISTER,
+            pos: None
+        })
+    );
+    assert_eq!(
+        capture(".ga"),
+        Some(Commands::Put {
+            reg: 'a',
+            pos: None
+        })
+    );
+    assert_eq!(
+        capture(".ga 3"),
+        Some(Commands::Put {
+            reg: 'a',
+            pos: Some(3)
+        })
+    );
+    assert_eq!(
+        capture(".g 0x3"),
+        Some(Commands::Put {
+            reg: DEFAULT_REGISTER,
+            pos: Some(3)
+        })
+    );
+    assert_eq!(capture(".ga3"), None);
+    assert_eq!(capture(".g-"), None);
+}
+
+#[test]
+fn parse_compare() {
+    assert_eq!(
+        capture(".= nfd"),
+        Some(Commands::Compare(Source::Buffer("nfd".to_string())))
+    );
+    assert_eq!(
+        capture(".=o ./nya"),
+        Some(Commands::Compare(Source::File("./nya".to_string())))
+    );
+    assert_eq!(capture(".="), None);
+    assert_eq!(capture(".=nfd"), None);
+    assert_eq!(capture(".=o./nya"), None);
+    assert_eq!(capture(".= a b"), None);
+}
+
+#[test]
+fn parse_diff() {
+    assert_eq!(
+        capture(".~ nfd"),
+        Some(Commands::Diff(Source::Buffer("nfd".to_string())))
+    );
+    assert_eq!(
+        capture(".~o ./nya"),
+        Some(Commands::Diff(Source::File("./nya".to_string())))
+    );
+    assert_eq!(capture(".~"), None);
+    assert_eq!(capture(".~x ./nya"), None);
+}
+
+#[test]
+fn parse_bytes() {
+    assert_eq!(
+        capture(".b utf8 E2 82 AC F0 9F"),
+        Some(Commands::DecodeBytes {
+            enc: EncodingType::UTF8,
+            bytes: [0xE2, 0x82, 0xAC, 0xF0, 0x9F].to_vec()
+        })
+    );
+    assert_eq!(
+        capture(".b UTF-16LE 3dd800de"),
+        Some(Commands::DecodeBytes {
+            enc: EncodingType::UTF16LE,
+            bytes: [0x3D, 0xD8, 0x00, 0xDE].to_vec()
+        })
+    );
+    assert_eq!(
+        capture(".b64 utf32 AAAAYQ=="),
+        Some(Commands::DecodeBytes {
+            enc: EncodingType::UTF32,
+            bytes: [0x00, 0x00, 0x00, 0x61].to_vec()
+        })
+    );
+    assert_eq!(capture(".b utf8 E2 8"), None);
+    assert_eq!(capture(".b utf8 ZZ"), None);
+    assert_eq!(capture(".b utf8"), None);
+    assert_eq!(
+        capture(".b shift_jis 82 A0"),
+        Some(Commands::DecodeBytes {
+            enc: EncodingType::Legacy(encoding_rs::SHIFT_JIS),
+            bytes: [0x82, 0xA0].to_vec()
+        })
+    );
+    assert_eq!(capture(".b utf9 E2"), None);
+    assert_eq!(capture(".b64 utf8 A"), None);
+    assert_eq!(capture(".butf8 E2"), None);
+}
+
+#[test]
+fn parse_units() {
+    assert_eq!(
+        capture(".u D83D DE00"),
+        Some(Commands::DecodeUnits([0xD83D, 0xDE00].to_vec()))
+    );
+    assert_eq!(
+        capture(".u \\uD83D\\uDE00"),
+        Some(Commands::DecodeUnits([0xD83D, 0xDE00].to_vec()))
+    );
+    assert_eq!(
+        capture(".u 0x61 \\u0062"),
+        Some(Commands::DecodeUnits([0x61, 0x62].to_vec()))
+    );
+    assert_eq!(capture(".u"), None);
+    assert_eq!(capture(".u 1F600"), None);
+    assert_eq!(capture(".u D83G"), None);
+    assert_eq!(capture(".uD83D"), None);
+}
+
+#[test]
+fn parse_mojibake() {
+    assert_eq!(capture(".f"), Some(Commands::MojibakeDetect));
+    assert_eq!(
+        capture(".f windows-1252 utf8"),
+        Some(Commands::MojibakeRepair {
+            wrong: EncodingType::Legacy(encoding_rs::WINDOWS_1252),
+            right: EncodingType::UTF8
+        })
+    );
+    assert_eq!(
+        capture(".f latin1 UTF-8"),
+        Some(Commands::MojibakeRepair {
+            wrong: EncodingType::Latin1,
+            right: EncodingType::UTF8
+        })
+    );
+    assert_eq!(capture(".f windows-1252"), None);
+    assert_eq!(capture(".f nothing utf8"), None);
+    assert_eq!(capture(".fx"), None);
+}
+
+#[test]
+fn parse_read() {
+    assert_eq!(
+        capture(".o ./nyaaa"),
+        Some(Commands::Read {
+            enc: EncodingType::UTF8,
+            file: "./nyaaa".to_string(),
+        })
+    );
+    assert_eq!(
+        capture(".o16le ./nyaaa"),
+        Some(Commands::Read {
+            enc: EncodingType::UTF16LE,
+            file: "./nyaaa".to_string(),
+        })
+    );
+    assert_eq!(
+        capture(".oeuc-kr ./nyaaa"),
+        Some(Commands::Read {
+            enc: EncodingType::Legacy(encoding_rs::EUC_KR),
+            file: "./nyaaa".to_string(),
+        })
+    );
+    assert_eq!(
+        capture(".o7imap ./nyaaa"),
+        Some(Commands::Read {
+            enc: EncodingType::IMAPUTF7,
+            file: "./nyaaa".to_string(),
+        })
+    );
+    assert_eq!(
+        capture(".outf-7 ./nyaaa"),
+        Some(Commands::Read {
+            enc: EncodingType::UTF7,
+            file: "./nyaaa".to_string(),
+        })
+    );
+    assert_eq!(
+        capture(".oBOCU-1 ./nyaaa"),
+        Some(Commands::Read {
+            enc: EncodingType::BOCU1,
+            file: "./nyaaa".to_string(),
+        })
+    );
+    assert_eq!(capture(".o./nyaaa"), None);
+    assert_eq!(capture(".o99 ./nyaaa"), None);
+}
+#[test]
+fn parse_idna() {
+    assert_eq!(capture(".a"), Some(Commands::IdnaAscii));
+    assert_eq!(capture(".au"), Some(Commands::IdnaUnicode(None)));
+    assert_eq!(
+        capture(".au xn--bcher-kva"),
+        Some(Commands::IdnaUnicode(Some("xn--bcher-kva".to_string())))
+    );
+    assert_eq!(capture(".au xn--a xn--b"), None);
+    assert_eq!(capture(".ax"), None);
+    assert_eq!(capture(".auxn--bcher-kva"), None);
+}
+#[test]
+fn parse_transfer_render() {
+    assert_eq!(
+        capture(".r%"),
+        Some(Commands::RenderPercent { query: false })
+    );
+    assert_eq!(
+        capture(".r%q"),
+        Some(Commands::RenderPercent { query: true })
+    );
+    assert_eq!(capture(".r%x"), None);
+    assert_eq!(
+        capture(".r64"),
+        Some(Commands::RenderBase64(EncodingType::UTF8))
+    );
+    assert_eq!(
+        capture(".r64 16le"),
+        Some(Commands::RenderBase64(EncodingType::UTF16LE))
+    );
+    assert_eq!(
+        capture(".r64 shift_jis"),
+        Some(Commands::RenderBase64(EncodingType::Legacy(
+            encoding_rs::SHIFT_JIS
+        )))
+    );
+    assert_eq!(capture(".r64x"), None);
+    assert_eq!(
+        capture(".rmq"),
+        Some(Commands::RenderMime {
+            enc: EncodingType::UTF8,
+            q: true
+        })
+    );
+    assert_eq!(
+        capture(".rmb latin1"),
+        Some(Commands::RenderMime {
+            enc: EncodingType::Latin1,
+            q: false
+        })
+    );
+    assert_eq!(capture(".rmb 7imap"), None);
+    assert_eq!(capture(".rm"), None);
+}
+#[test]
+fn parse_transfer_bytes() {
+    assert_eq!(
+        capture(".b% utf8 caf%C3%A9"),
+        Some(Commands::DecodeBytes {
+            enc: EncodingType::UTF8,
+            bytes: "café".as_bytes().to_vec()
+        })
+    );
+    assert_eq!(
+        capture(".b%q latin1 a+b%E9"),
+        Some(Commands::DecodeBytes {
+            enc: EncodingType::Latin1,
+            bytes: b"a b\xE9".to_vec()
+        })
+    );
+    assert_eq!(capture(".b% utf8 %E"), None);
+    assert_eq!(
+        capture(".bm =?UTF-8?Q?caf=C3=A9?= ok"),
+        Some(Commands::DecodeMime(vec![
+            (EncodingType::UTF8, "café".as_bytes().to_vec()),
+            (EncodingType::UTF8, b" ok".to_vec())
+        ]))
+    );
+    assert_eq!(capture(".bm =?nyaaa?Q?a?="), None);
+    assert_eq!(capture(".bm"), None);
+}
+#[test]
+fn parse_case() {
+    assert_eq!(
+        capture(".tu"),
+        Some(Commands::Transform {
+            kind: Transform::Upper(CaseLocale::Root),
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".tl tr"),
+        Some(Commands::Transform {
+            kind: Transform::Lower(CaseLocale::Turkic),
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".tt lt 2 0x10"),
+        Some(Commands::Transform {
+            kind: Transform::Title(CaseLocale::Lithuanian),
+            range: Some((2, 16))
+        })
+    );
+    assert_eq!(
+        capture(".tu 1 3"),
+        Some(Commands::Transform {
+            kind: Transform::Upper(CaseLocale::Root),
+            range: Some((1, 3))
+        })
+    );
+    assert_eq!(capture(".tu 3 1"), None);
+    assert_eq!(capture(".tu de"), None);
+    assert_eq!(capture(".tu az 1"), None);
+    assert_eq!(capture(".tx"), None);
+}
+#[test]
+fn parse_fold() {
+    assert_eq!(
+        capture(".td"),
+        Some(Commands::Transform {
+            kind: Transform::StripMarks,
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".ta 0 4"),
+        Some(Commands::Transform {
+            kind: Transform::AsciiFold,
+            range: Some((0, 4))
+        })
+    );
+    assert_eq!(capture(".ta tr"), None);
+    assert_eq!(capture(".td4"), None);
+}
+#[test]
+fn parse_width() {
+    assert_eq!(
+        capture(".tw"),
+        Some(Commands::Transform {
+            kind: Transform::Fullwidth,
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".tn 1 2"),
+        Some(Commands::Transform {
+            kind: Transform::Halfwidth,
+            range: Some((1, 2))
+        })
+    );
+    assert_eq!(
+        capture(".tk"),
+        Some(Commands::Transform {
+            kind: Transform::Katakana,
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".th 0 0"),
+        Some(Commands::Transform {
+            kind: Transform::Hiragana,
+            range: Some((0, 0))
+        })
+    );
+}
+#[test]
+fn parse_styled() {
+    assert_eq!(
+        capture(".ts bold"),
+        Some(Commands::Transform {
+            kind: Transform::Styled(Style::Bold),
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".ts Fraktur 0 3"),
+        Some(Commands::Transform {
+            kind: Transform::Styled(Style::Fraktur),
+            range: Some((0, 3))
+        })
+    );
+    assert_eq!(
+        capture(".ts plain"),
+        Some(Commands::Transform {
+            kind: Transform::Plain,
+            range: None
+        })
+    );
+    assert_eq!(capture(".ts"), None);
+    assert_eq!(capture(".ts comic"), None);
+    assert_eq!(capture(".tsbold"), None);
+}
+#[test]
+fn parse_latin() {
+    assert_eq!(
+        capture(".tr"),
+        Some(Commands::Transform {
+            kind: Transform::Latin,
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".tr 2 5"),
+        Some(Commands::Transform {
+            kind: Transform::Latin,
+            range: Some((2, 5))
+        })
+    );
+    assert_eq!(capture(".tr ru"), None);
+}
+#[test]
+fn parse_hangul() {
+    assert_eq!(
+        capture(".j 18 0 4"),
+        Some(Commands::HangulCompose {
+            lead: 18,
+            vowel: 0,
+            tail: 4
+        })
+    );
+    assert_eq!(
+        capture(".j 0x12 0"),
+        Some(Commands::HangulCompose {
+            lead: 18,
+            vowel: 0,
+            tail: 0
+        })
+    );
+    assert_eq!(
+        capture(".jr han-geul"),
+        Some(Commands::HangulRoman("han-geul".to_string()))
+    );
+    assert_eq!(capture(".jd 3"), Some(Commands::HangulDecompose { pos: 3 }));
+    assert_eq!(capture(".j 18"), None);
+    assert_eq!(capture(".j 1 2 3 4"), None);
+    assert_eq!(capture(".jr"), None);
+    assert_eq!(capture(".jd"), None);
+}
+#[test]
+fn parse_emoji() {
+    assert_eq!(
+        capture(".:m 👍 medium"),
+        Some(Commands::EmojiModifier {
+            base: 0x1F44D,
+            tone: SkinTone::Medium
+        })
+    );
+    assert_eq!(
+        capture(".:m x1F44D 5"),
+        Some(Commands::EmojiModifier {
+            base: 0x1F44D,
+            tone: SkinTone::Dark
+        })
+    );
+    assert_eq!(
+        capture(".:z 👩 x1F4BB"),
+        Some(Commands::EmojiZwj(vec![vec![0x1F469], vec![0x1F4BB]]))
+    );
+    assert_eq!(
+        capture(".:f jp"),
+        Some(Commands::EmojiFlag("JP".to_string()))
+    );
+    assert_eq!(
+        capture(".:t GB-SCT"),
+        Some(Commands::EmojiSubdivision("gbsct".to_string()))
+    );
+    assert_eq!(capture(".:k #"), Some(Commands::EmojiKeycap('#')));
+    assert_eq!(capture(".:v"), Some(Commands::EmojiCheck));
+    assert_eq!(capture(".:m 👍 pale"), None);
+    assert_eq!(capture(".:z 👩"), None);
+    assert_eq!(capture(".:f jpn"), None);
+    assert_eq!(capture(".:t 12x"), None);
+    assert_eq!(capture(".:k 10"), None);
+    assert_eq!(capture(".:v 1"), None);
+}
+#[test]
+fn parse_variation() {
+    assert_eq!(capture(".v"), Some(Commands::Valid));
+    assert_eq!(capture(".vs"), Some(Commands::VariationList));
+    assert_eq!(
+        capture(".vse"),
+        Some(Commands::Transform {
+            kind: Transform::EmojiPresentation,
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".vst 1 4"),
+        Some(Commands::Transform {
+            kind: Transform::TextPresentation,
+            range: Some((1, 4))
+        })
+    );
+    assert_eq!(
+        capture(".vsd"),
+        Some(Commands::Transform {
+            kind: Transform::DefaultPresentation,
+            range: None
+        })
+    );
+    assert_eq!(
+        capture(".vsx"),
+        Some(Commands::Transform {
+            kind: Transform::StripSelectors,
+            range: None
+        })
+    );
+    assert_eq!(capture(".vs 1"), None);
+    assert_eq!(capture(".vsq"), None);
+    assert_eq!(capture(".vx"), None);
+}
+#[test]
+fn parse_confusable() {
+    assert_eq!(capture(".vc"), Some(Commands::Confusable(None)));
+    assert_eq!(
+        capture(".vc other"),
+        Some(Commands::Confusable(Some(Source::Buffer(
+            "other".to_string()
+        ))))
+    );
+    assert_eq!(
+        capture(".vco names.txt"),
+        Some(Commands::Confusable(Some(Source::File(
+            "names.txt".to_string()
+        ))))
+    );
+    assert_eq!(capture(".vcx"), None);
+}
+#[test]
+fn parse_reveal() {
+    assert_eq!(capture(".rv"), Some(Commands::RenderReveal));
+    assert_eq!(capture(".rv 16"), None);
+    assert_eq!(capture(".vb"), Some(Commands::BidiCheck));
+    assert_eq!(capture(".vbx"), None);
+}
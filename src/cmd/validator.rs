@@ -21,7 +21,13 @@ This check is valid for the following commmand:
 + `c` Commands::Compress
 + `d` Commands::Decompress
 + `e` Commands::Erase
++ `rv` Commands::RenderReveal
 + `v` Commands::Valid
++ `vs` Commands::VariationList
++ `vc` Commands::Confusable
++ `vb` Commands::BidiCheck
++ `z` Commands::Size
++ `:v` Commands::EmojiCheck
 
  */
 pub fn final_check(text: std::str::Chars, parsed: Commands) -> Option<Commands> {
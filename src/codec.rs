@@ -0,0 +1,392 @@
+/*! Conversion between the encoded bytes and the codepoints
+
+Encoding fails when any codepoint is not representable in the encoding,
+unless a [Fallback] is used to replace those codepoints.
+
+Decoding never fails as a whole. Each invalid or truncated sequence is replaced
+with `U+FFFD` in the output and recorded with its byte offset.
+
+The legacy encodings are handled by `encoding_rs`, except ISO-8859-1 which is
+treated as a plain mapping of the first 256 codepoints rather than as Windows-1252.
+ */
+
+mod bocu1;
+mod scsu;
+#[cfg(test)]
+mod testing;
+mod utf7;
+
+use crate::cmd::{EncodingType, Fallback};
+
+/// Codepoint inserted in place of the invalid sequence
+pub const REPLACEMENT: u32 = 0xFFFD;
+
+/// Codepoint of the byte order mark
+pub const BOM: u32 = 0xFEFF;
+
+/// Invalid or truncated sequence found while decoding
+#[derive(Debug, Eq, PartialEq)]
+pub struct DecodeError {
+    /// Offset of the first byte of the sequence
+    pub offset: usize,
+    /// Bytes of the sequence
+    pub bytes: Vec<u8>,
+    /// The sequence is a valid prefix cut by the end of the input
+    pub truncated: bool,
+}
+
+/// Reason of the failure to encode a file
+#[derive(Debug, Eq, PartialEq)]
+pub enum EncodeError {
+    /// The codepoints at the positions are not representable in the encoding
+    Unrepresentable(Vec<usize>),
+    /// The encoding has no byte order mark
+    NoBom,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Decoded {
+    pub codepoints: Vec<u32>,
+    pub errors: Vec<DecodeError>,
+}
+
+pub fn decode(bytes: &[u8], enc: &EncodingType) -> Decoded {
+    match enc {
+        EncodingType::UTF8 => decode_utf8(bytes),
+        EncodingType::UTF16 => decode_utf16(bytes, true),
+        EncodingType::UTF16LE => decode_utf16(bytes, false),
+        EncodingType::UTF32 => decode_utf32(bytes, true),
+        EncodingType::UTF32LE => decode_utf32(bytes, false),
+        EncodingType::UTF7 => utf7::decode(bytes, false),
+        EncodingType::IMAPUTF7 => utf7::decode(bytes, true),
+        EncodingType::SCSU => scsu::decode(bytes),
+        EncodingType::BOCU1 => bocu1::decode(bytes),
+        EncodingType::Latin1 => Decoded {
+            codepoints: bytes.iter().map(|&x| x as u32).collect(),
+            errors: Vec::new(),
+        },
+        EncodingType::Legacy(enc) => decode_legacy(bytes, enc),
+    }
+}
+
+/** Encode the codepoints with the encoding
+
+When any codepoint is not representable in the encoding,
+the positions of all of the unrepresentable codepoints are returned instead.
+ */
+pub fn encode(buff: &[u32], enc: &EncodingType) -> Result<Vec<u8>, Vec<usize>> {
+    let chars: Vec<Option<char>> = buff.iter().map(|&x| char::from_u32(x)).collect();
+    match enc {
+        EncodingType::Latin1 => {
+            let unmappable = positions(&chars, |x| x as u32 > 0xFF);
+            if !unmappable.is_empty() {
+                return Err(unmappable);
+            }
+            return Ok(buff.iter().map(|&x| x as u8).collect());
+        }
+        EncodingType::Legacy(enc) => return encode_legacy(&chars, enc),
+        _ => (),
+    }
+    let invalid = positions(&chars, |_| false);
+    if !invalid.is_empty() {
+        return Err(invalid);
+    }
+    let text: String = chars.iter().flatten().collect();
+    Ok(match enc {
+        EncodingType::UTF8 => text.into_bytes(),
+        EncodingType::UTF16 => text.encode_utf16().flat_map(|x| x.to_be_bytes()).collect(),
+        EncodingType::UTF16LE => text.encode_utf16().flat_map(|x| x.to_le_bytes()).collect(),
+        EncodingType::UTF32 => bitsplitter(buff, true),
+        EncodingType::UTF32LE => bitsplitter(buff, false),
+        EncodingType::UTF7 => utf7::encode(&text, false),
+        EncodingType::IMAPUTF7 => utf7::encode(&text, true),
+        EncodingType::SCSU => scsu::encode(&text),
+        EncodingType::BOCU1 => bocu1::encode(&text),
+        EncodingType::Latin1 | EncodingType::Legacy(_) => unreachable!(),
+    })
+}
+
+/** Encode the codepoints as the content of a file
+
+The byte order mark is `U+FEFF` encoded with the encoding, written before the content.
+The unrepresentable codepoints are handled as in [encode_with_fallback].
+ */
+pub fn encode_file(
+    buff: &[u32],
+    enc: &EncodingType,
+    fallback: Fallback,
+    bom: bool,
+) -> Result<(Vec<u8>, Vec<usize>), EncodeError> {
+    let mut bytes = if bom {
+        encode(&[BOM], enc).map_err(|_| EncodeError::NoBom)?
+    } else {
+        Vec::new()
+    };
+    let (mut content, pos) =
+        encode_with_fallback(buff, enc, fallback).map_err(EncodeError::Unrepresentable)?;
+    bytes.append(&mut content);
+    Ok((bytes, pos))
+}
+
+/** Decode the content of a file
+
+The leading byte order mark of the encoding is skipped,
+the offsets of the errors are still counted from the start of the file.
+ */
+pub fn decode_file(bytes: &[u8], enc: &EncodingType) -> Decoded {
+    let skip = match encode(&[BOM], enc) {
+        Ok(bom) if bytes.starts_with(&bom) => bom.len(),
+        _ => 0,
+    };
+    let mut decoded = decode(&bytes[skip..], enc);
+    for err in decoded.errors.iter_mut() {
+        err.offset += skip;
+    }
+    decoded
+}
+
+/// Split each codepoint to the four bytes of UTF-32
+pub fn bitsplitter(inp: &[u32], big_endian: bool) -> Vec<u8> {
+    inp.iter()
+        .flat_map(|val| {
+            if big_endian {
+                val.to_be_bytes()
+            } else {
+                val.to_le_bytes()
+            }
+        })
+        .collect()
+}
+
+/** Encode the codepoints, replacing the unrepresentable codepoints with the fallback
+
+The positions of the unrepresentable codepoints are returned alongside the bytes.
+With [Fallback::Error], only the positions are returned.
+ */
+pub fn encode_with_fallback(
+    buff: &[u32],
+    enc: &EncodingType,
+    fallback: Fallback,
+) -> Result<(Vec<u8>, Vec<usize>), Vec<usize>> {
+    let unmappable = match encode(buff, enc) {
+        Ok(bytes) => return Ok((bytes, Vec::new())),
+        Err(pos) => pos,
+    };
+    let mut substituted = Vec::new();
+    let mut next = unmappable.iter().peekable();
+    for (idx, &cp) in buff.iter().enumerate() {
+        if next.next_if_eq(&&idx).is_none() {
+            substituted.push(cp);
+            continue;
+        }
+        match fallback {
+            Fallback::Error => return Err(unmappable),
+            Fallback::Question => substituted.push('?' as u32),
+            Fallback::NumericReference => {
+                substituted.extend(format!("&#{};", cp).chars().map(|x| x as u32))
+            }
+        }
+    }
+    Ok((encode(&substituted, enc)?, unmappable))
+}
+
+/// Positions of the invalid codepoints, or the valid one matching the predicate
+fn positions<F>(chars: &[Option<char>], pred: F) -> Vec<usize>
+where
+    F: Fn(char) -> bool,
+{
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| x.is_none_or(&pred))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn encode_legacy(
+    chars: &[Option<char>],
+    enc: &'static encoding_rs::Encoding,
+) -> Result<Vec<u8>, Vec<usize>> {
+    let mut encoder = enc.new_encoder();
+    let mut bytes = Vec::new();
+    let mut unmappable = Vec::new();
+    let mut chunk = [0u8; 16];
+    let mut push = |encoder: &mut encoding_rs::Encoder, text: &str, last: bool| -> bool {
+        let mut input = text;
+        loop {
+            let (res, read, written) =
+                encoder.encode_from_utf8_without_replacement(input, &mut chunk, last);
+            bytes.extend_from_slice(&chunk[..written]);
+            input = &input[read..];
+            match res {
+                encoding_rs::EncoderResult::InputEmpty => return true,
+                encoding_rs::EncoderResult::OutputFull => continue,
+                encoding_rs::EncoderResult::Unmappable(_) => return false,
+            }
+        }
+    };
+    for (idx, chr) in chars.iter().enumerate() {
+        let mappable = match chr {
+            Some(chr) => push(&mut encoder, chr.encode_utf8(&mut [0; 4]), false),
+            None => false,
+        };
+        if !mappable {
+            unmappable.push(idx);
+        }
+    }
+    push(&mut encoder, "", true);
+    if unmappable.is_empty() {
+        Ok(bytes)
+    } else {
+        Err(unmappable)
+    }
+}
+
+fn decode_legacy(bytes: &[u8], enc: &'static encoding_rs::Encoding) -> Decoded {
+    let mut decoder = enc.new_decoder_without_bom_handling();
+    let mut codepoints = Vec::new();
+    let mut errors = Vec::new();
+    let mut text = String::new();
+    let mut read_total = 0;
+    loop {
+        text.reserve(
+            decoder
+                .max_utf8_buffer_length_without_replacement(bytes.len() - read_total)
+                .unwrap_or(16),
+        );
+        let (res, read) =
+            decoder.decode_to_string_without_replacement(&bytes[read_total..], &mut text, true);
+        read_total += read;
+        codepoints.extend(text.drain(..).map(|x| x as u32));
+        match res {
+            encoding_rs::DecoderResult::InputEmpty => break,
+            encoding_rs::DecoderResult::OutputFull => continue,
+            encoding_rs::DecoderResult::Malformed(bad, extra) => {
+                let offset = read_total - extra as usize - bad as usize;
+                codepoints.push(REPLACEMENT);
+                errors.push(DecodeError {
+                    offset,
+                    bytes: bytes[offset..offset + bad as usize].to_vec(),
+                    truncated: read_total == bytes.len() && extra == 0,
+                });
+            }
+        }
+    }
+    Decoded { codepoints, errors }
+}
+
+fn decode_utf8(bytes: &[u8]) -> Decoded {
+    let mut codepoints = Vec::new();
+    let mut errors = Vec::new();
+    let mut offset = 0;
+    for chunk in bytes.utf8_chunks() {
+        codepoints.extend(chunk.valid().chars().map(|x| x as u32));
+        offset += chunk.valid().len();
+        let invalid = chunk.invalid();
+        if !invalid.is_empty() {
+            codepoints.push(REPLACEMENT);
+            errors.push(DecodeError {
+                offset,
+                bytes: invalid.to_vec(),
+                truncated: offset + invalid.len() == bytes.len()
+                    && std::str::from_utf8(invalid)
+                        .err()
+                        .is_some_and(|x| x.error_len().is_none()),
+            });
+            offset += invalid.len();
+        }
+    }
+    Decoded { codepoints, errors }
+}
+
+fn decode_utf16(bytes: &[u8], big_endian: bool) -> Decoded {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|x| {
+            if big_endian {
+                u16::from_be_bytes([x[0], x[1]])
+            } else {
+                u16::from_le_bytes([x[0], x[1]])
+            }
+        })
+        .collect();
+    let (codepoints, unpaired) = decode_utf16_units(&units);
+    let mut errors: Vec<DecodeError> = unpaired
+        .into_iter()
+        .map(|idx| DecodeError {
+            offset: idx * 2,
+            bytes: bytes[idx * 2..idx * 2 + 2].to_vec(),
+            truncated: false,
+        })
+        .collect();
+    let mut codepoints = codepoints;
+    push_remainder(bytes, 2, &mut codepoints, &mut errors);
+    Decoded { codepoints, errors }
+}
+
+/** Join the UTF-16 code units to codepoints
+
+Every unpaired surrogate is replaced with `U+FFFD`,
+and its index in the code units is returned alongside the codepoints.
+ */
+pub fn decode_utf16_units(units: &[u16]) -> (Vec<u32>, Vec<usize>) {
+    let mut codepoints = Vec::new();
+    let mut unpaired = Vec::new();
+    let mut idx = 0;
+    for chr in char::decode_utf16(units.iter().copied()) {
+        match chr {
+            Ok(chr) => {
+                codepoints.push(chr as u32);
+                idx += chr.len_utf16();
+            }
+            Err(_) => {
+                codepoints.push(REPLACEMENT);
+                unpaired.push(idx);
+                idx += 1;
+            }
+        }
+    }
+    (codepoints, unpaired)
+}
+
+fn decode_utf32(bytes: &[u8], big_endian: bool) -> Decoded {
+    let mut codepoints = Vec::new();
+    let mut errors = Vec::new();
+    for (idx, x) in bytes.chunks_exact(4).enumerate() {
+        let val = if big_endian {
+            u32::from_be_bytes([x[0], x[1], x[2], x[3]])
+        } else {
+            u32::from_le_bytes([x[0], x[1], x[2], x[3]])
+        };
+        if char::from_u32(val).is_some() {
+            codepoints.push(val);
+        } else {
+            codepoints.push(REPLACEMENT);
+            errors.push(DecodeError {
+                offset: idx * 4,
+                bytes: x.to_vec(),
+                truncated: false,
+            });
+        }
+    }
+    push_remainder(bytes, 4, &mut codepoints, &mut errors);
+    Decoded { codepoints, errors }
+}
+
+/// Record the trailing bytes which do not fill a whole code unit
+fn push_remainder(
+    bytes: &[u8],
+    unit: usize,
+    codepoints: &mut Vec<u32>,
+    errors: &mut Vec<DecodeError>,
+) {
+    let rest = bytes.len() % unit;
+    if rest != 0 {
+        codepoints.push(REPLACEMENT);
+        errors.push(DecodeError {
+            offset: bytes.len() - rest,
+            bytes: bytes[bytes.len() - rest..].to_vec(),
+            truncated: true,
+        });
+    }
+}
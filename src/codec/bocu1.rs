@@ -0,0 +1,185 @@
+/*! Binary Ordered Compression for Unicode, as of UTN #6
+
+Each codepoint is written as its difference from the previous codepoint,
+adjusted to the middle of its script block.
+ */
+
+use super::{DecodeError, Decoded, REPLACEMENT};
+
+const MIDDLE: i32 = 0x90;
+const RESET: u8 = 0xFF;
+const ASCII_PREV: i32 = 0x40;
+const TRAIL_CONTROLS: i32 = 20;
+const TRAIL_OFFSET: i32 = 0x21 - TRAIL_CONTROLS;
+const TRAIL_COUNT: i32 = (0xFF - 0x21 + 1) + TRAIL_CONTROLS;
+
+const REACH_POS_1: i32 = 63;
+const REACH_NEG_1: i32 = -64;
+const REACH_POS_2: i32 = REACH_POS_1 + 43 * TRAIL_COUNT;
+const REACH_NEG_2: i32 = REACH_NEG_1 - 43 * TRAIL_COUNT;
+const REACH_POS_3: i32 = REACH_POS_2 + 3 * TRAIL_COUNT * TRAIL_COUNT;
+const REACH_NEG_3: i32 = REACH_NEG_2 - 3 * TRAIL_COUNT * TRAIL_COUNT;
+
+const START_POS_2: i32 = MIDDLE + REACH_POS_1 + 1;
+const START_POS_3: i32 = START_POS_2 + 43;
+const START_POS_4: i32 = START_POS_3 + 3;
+const START_NEG_2: i32 = MIDDLE + REACH_NEG_1;
+const START_NEG_3: i32 = START_NEG_2 - 43;
+const START_NEG_4: i32 = START_NEG_3 - 3;
+
+/// The control bytes usable as trail byte, the other trail values are offset from `0x21`
+const TRAIL_TO_BYTE: [u8; TRAIL_CONTROLS as usize] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+    0x1C, 0x1D, 0x1E, 0x1F,
+];
+
+fn trail_to_byte(trail: i32) -> u8 {
+    if trail < TRAIL_CONTROLS {
+        TRAIL_TO_BYTE[trail as usize]
+    } else {
+        (trail + TRAIL_OFFSET) as u8
+    }
+}
+
+fn byte_to_trail(byte: u8) -> Option<i32> {
+    if byte > 0x20 {
+        Some(byte as i32 - TRAIL_OFFSET)
+    } else {
+        TRAIL_TO_BYTE
+            .iter()
+            .position(|&x| x == byte)
+            .map(|x| x as i32)
+    }
+}
+
+/// Previous value used for the next difference, set to the middle of the block of the codepoint
+fn next_prev(cp: i32) -> i32 {
+    match cp {
+        0x3040..=0x309F => 0x3070,
+        0x4E00..=0x9FA5 => 0x4E00 - REACH_NEG_2,
+        0xAC00..=0xD7A3 => (0xD7A3 + 0xAC00) / 2,
+        _ => (cp & !0x7F) + ASCII_PREV,
+    }
+}
+
+fn pack_diff(diff: i32, bytes: &mut Vec<u8>) {
+    let (mut diff, lead, count) = if diff >= REACH_NEG_1 {
+        if diff <= REACH_POS_1 {
+            bytes.push((MIDDLE + diff) as u8);
+            return;
+        } else if diff <= REACH_POS_2 {
+            (diff - (REACH_POS_1 + 1), START_POS_2, 1)
+        } else if diff <= REACH_POS_3 {
+            (diff - (REACH_POS_2 + 1), START_POS_3, 2)
+        } else {
+            (diff - (REACH_POS_3 + 1), START_POS_4, 3)
+        }
+    } else if diff >= REACH_NEG_2 {
+        (diff - REACH_NEG_1, START_NEG_2, 1)
+    } else if diff >= REACH_NEG_3 {
+        (diff - REACH_NEG_2, START_NEG_3, 2)
+    } else {
+        (diff - REACH_NEG_3, START_NEG_4, 3)
+    };
+    let mut trails = Vec::new();
+    for _ in 0..count {
+        trails.push(trail_to_byte(diff.rem_euclid(TRAIL_COUNT)));
+        diff = diff.div_euclid(TRAIL_COUNT);
+    }
+    bytes.push((lead + diff) as u8);
+    bytes.extend(trails.iter().rev());
+}
+
+pub fn encode(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut prev = ASCII_PREV;
+    for chr in text.chars() {
+        let cp = chr as i32;
+        if cp <= 0x20 {
+            if cp != 0x20 {
+                prev = ASCII_PREV;
+            }
+            bytes.push(cp as u8);
+            continue;
+        }
+        pack_diff(cp - prev, &mut bytes);
+        prev = next_prev(cp);
+    }
+    bytes
+}
+
+pub fn decode(bytes: &[u8]) -> Decoded {
+    let mut codepoints = Vec::new();
+    let mut errors = Vec::new();
+    let mut prev = ASCII_PREV;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let lead = bytes[idx] as i32;
+        if lead <= 0x20 {
+            if lead != 0x20 {
+                prev = ASCII_PREV;
+            }
+            codepoints.push(lead as u32);
+            idx += 1;
+            continue;
+        }
+        if lead == RESET as i32 {
+            prev = ASCII_PREV;
+            idx += 1;
+            continue;
+        }
+        let (base, count) = if (START_NEG_2..START_POS_2).contains(&lead) {
+            (lead - MIDDLE, 0)
+        } else if lead >= START_POS_2 {
+            if lead < START_POS_3 {
+                ((lead - START_POS_2) * TRAIL_COUNT + REACH_POS_1 + 1, 1)
+            } else if lead < START_POS_4 {
+                (
+                    (lead - START_POS_3) * TRAIL_COUNT * TRAIL_COUNT + REACH_POS_2 + 1,
+                    2,
+                )
+            } else {
+                (REACH_POS_3 + 1, 3)
+            }
+        } else if lead >= START_NEG_3 {
+            ((lead - START_NEG_2) * TRAIL_COUNT + REACH_NEG_1, 1)
+        } else if lead >= START_NEG_4 {
+            (
+                (lead - START_NEG_3) * TRAIL_COUNT * TRAIL_COUNT + REACH_NEG_2,
+                2,
+            )
+        } else {
+            (-TRAIL_COUNT * TRAIL_COUNT * TRAIL_COUNT + REACH_NEG_3, 3)
+        };
+        let trails = bytes.get(idx + 1..idx + 1 + count);
+        let diff = trails.and_then(|trails| {
+            trails
+                .iter()
+                .try_fold(0, |acc, &x| Some(acc * TRAIL_COUNT + byte_to_trail(x)?))
+        });
+        let cp = diff.map(|x| prev + base + x);
+        match cp.and_then(|x| char::from_u32(x as u32)) {
+            Some(chr) => {
+                codepoints.push(chr as u32);
+                prev = next_prev(chr as i32);
+                idx += 1 + count;
+            }
+            None => {
+                let truncated = trails.is_none();
+                let end = if truncated {
+                    bytes.len()
+                } else {
+                    idx + 1 + count
+                };
+                codepoints.push(REPLACEMENT);
+                errors.push(DecodeError {
+                    offset: idx,
+                    bytes: bytes[idx..end].to_vec(),
+                    truncated,
+                });
+                idx = end;
+            }
+        }
+    }
+    Decoded { codepoints, errors }
+}
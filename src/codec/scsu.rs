@@ -0,0 +1,293 @@
+/*! Standard Compression Scheme for Unicode, as of UTS #6
+
+The encoder is a simple one, selecting the windows greedily on each codepoint.
+It uses the Unicode mode only for the BMP codepoints which are not reachable by any window,
+such as the CJK ideographs and the Hangul syllables.
+The decoder accepts every feature of the scheme.
+ */
+
+use super::{DecodeError, Decoded, REPLACEMENT};
+
+const SQ0: u8 = 0x01;
+const SDX: u8 = 0x0B;
+const SQU: u8 = 0x0E;
+const SCU: u8 = 0x0F;
+const SC0: u8 = 0x10;
+const SD0: u8 = 0x18;
+const UC0: u8 = 0xE0;
+const UD0: u8 = 0xE8;
+const UQU: u8 = 0xF0;
+const UDX: u8 = 0xF1;
+const URS: u8 = 0xF2;
+
+const STATIC_WINDOWS: [u32; 8] = [
+    0x0000, 0x0080, 0x0100, 0x0300, 0x2000, 0x2080, 0x2100, 0x3000,
+];
+const DYNAMIC_WINDOWS: [u32; 8] = [
+    0x0080, 0x00C0, 0x0400, 0x0600, 0x0900, 0x3040, 0x30A0, 0xFF00,
+];
+
+/// Whether the byte is a character by itself in the single byte mode
+fn is_passthrough(cp: u32) -> bool {
+    matches!(cp, 0x00 | 0x09 | 0x0A | 0x0D | 0x20..=0x7F)
+}
+
+/// Number of the argument bytes following the tag, or the high byte in the unicode mode
+fn argument_length(tag: u8, unicode: bool) -> usize {
+    match (unicode, tag) {
+        (true, 0xE0..=0xE7) | (true, URS) => 0,
+        (true, UQU) | (true, UDX) => 2,
+        (true, _) => 1,
+        (false, 0x01..=0x08) | (false, 0x18..=0x1F) => 1,
+        (false, SDX) | (false, SQU) => 2,
+        (false, _) => 0,
+    }
+}
+
+/// Offset of the window defined by the byte following SDn or UDn
+fn window_offset(x: u8) -> Option<u32> {
+    match x {
+        0x01..=0x67 => Some(x as u32 * 0x80),
+        0x68..=0xA7 => Some(x as u32 * 0x80 + 0xAC00),
+        0xF9 => Some(0x00C0),
+        0xFA => Some(0x0250),
+        0xFB => Some(0x0370),
+        0xFC => Some(0x0530),
+        0xFD => Some(0x3040),
+        0xFE => Some(0x30A0),
+        0xFF => Some(0xFF60),
+        _ => None,
+    }
+}
+
+/// Byte defining the window aligned to 128 which contains the BMP codepoint
+fn window_byte(cp: u32) -> Option<u8> {
+    match cp {
+        0x0080..=0x337F => Some((cp >> 7) as u8),
+        0xE000..=0xFFFF => Some(((cp - 0xAC00) >> 7) as u8),
+        _ => None,
+    }
+}
+
+struct Encoder {
+    bytes: Vec<u8>,
+    windows: [u32; 8],
+    active: usize,
+    unicode: bool,
+    /// Window to be redefined next
+    next: usize,
+}
+
+impl Encoder {
+    fn window_of(&self, cp: u32) -> Option<usize> {
+        if (self.windows[self.active]..self.windows[self.active] + 0x80).contains(&cp) {
+            return Some(self.active);
+        }
+        self.windows
+            .iter()
+            .position(|&off| (off..off + 0x80).contains(&cp))
+    }
+
+    /// Define a new window containing the codepoint, and select it
+    fn define(&mut self, cp: u32, single: u8, extended: u8) {
+        let n = self.next;
+        self.next = (self.next + 1) % 8;
+        match window_byte(cp) {
+            Some(x) => {
+                self.bytes.extend([single + n as u8, x]);
+                self.windows[n] = window_offset(x).unwrap();
+            }
+            None => {
+                let val = (cp - 0x10000) >> 7;
+                let word = ((n as u32) << 13) | val;
+                self.bytes.extend([extended, (word >> 8) as u8, word as u8]);
+                self.windows[n] = 0x10000 + (val << 7);
+            }
+        }
+        self.active = n;
+    }
+
+    fn push(&mut self, cp: u32) {
+        let windowable = cp != 0xFEFF && (cp < 0x80 || window_byte(cp).is_some() || cp >= 0x10000);
+        if self.unicode {
+            if !windowable {
+                if (UC0..=URS).contains(&((cp >> 8) as u8)) {
+                    self.bytes.push(UQU);
+                }
+                self.bytes.extend([(cp >> 8) as u8, cp as u8]);
+                return;
+            }
+            self.unicode = false;
+            match self.window_of(cp) {
+                Some(n) => {
+                    self.active = n;
+                    self.bytes.push(UC0 + n as u8);
+                }
+                None if cp < 0x80 => self.bytes.push(UC0 + self.active as u8),
+                None => {
+                    self.define(cp, UD0, UDX);
+                }
+            }
+        }
+        if is_passthrough(cp) {
+            self.bytes.push(cp as u8);
+        } else if cp < 0x20 || cp == 0xFEFF {
+            // Quoted, the byte order mark is always written as the signature 0E FE FF
+            if cp < 0x20 {
+                self.bytes.extend([SQ0, cp as u8]);
+            } else {
+                self.bytes.extend([SQU, 0xFE, 0xFF]);
+            }
+        } else if let Some(n) = self.window_of(cp) {
+            if n != self.active {
+                self.active = n;
+                self.bytes.push(SC0 + n as u8);
+            }
+            self.bytes.push((cp - self.windows[n] + 0x80) as u8);
+        } else if windowable {
+            self.define(cp, SD0, SDX);
+            self.bytes
+                .push((cp - self.windows[self.active] + 0x80) as u8);
+        } else {
+            self.unicode = true;
+            self.bytes.push(SCU);
+            self.push(cp);
+        }
+    }
+}
+
+pub fn encode(text: &str) -> Vec<u8> {
+    let mut encoder = Encoder {
+        bytes: Vec::new(),
+        windows: DYNAMIC_WINDOWS,
+        active: 0,
+        unicode: false,
+        next: 0,
+    };
+    for chr in text.chars() {
+        encoder.push(chr as u32);
+    }
+    encoder.bytes
+}
+
+pub fn decode(bytes: &[u8]) -> Decoded {
+    // The UTF-16 code units from the unicode mode and SQU are kept in the output,
+    // and paired once the whole input is decoded
+    let mut output: Vec<(usize, usize, u32)> = Vec::new();
+    let mut errors = Vec::new();
+    let mut windows = DYNAMIC_WINDOWS;
+    let mut active = 0;
+    let mut unicode = false;
+    let mut start = 0;
+    while start < bytes.len() {
+        let tag = bytes[start];
+        let arg = |len: usize| bytes.get(start + 1..start + 1 + len);
+        // Length of the sequence, and the decoded codepoint if any
+        let step: Option<(usize, Option<u32>)> = if unicode {
+            match tag {
+                0xE0..=0xE7 => {
+                    active = (tag - UC0) as usize;
+                    unicode = false;
+                    Some((1, None))
+                }
+                0xE8..=0xEF => arg(1).and_then(|x| window_offset(x[0])).map(|off| {
+                    active = (tag - UD0) as usize;
+                    windows[active] = off;
+                    unicode = false;
+                    (2, None)
+                }),
+                UQU => arg(2).map(|x| (3, Some(u16::from_be_bytes([x[0], x[1]]) as u32))),
+                UDX => arg(2).map(|x| {
+                    let word = u16::from_be_bytes([x[0], x[1]]) as u32;
+                    active = (word >> 13) as usize;
+                    windows[active] = 0x10000 + ((word & 0x1FFF) << 7);
+                    unicode = false;
+                    (3, None)
+                }),
+                URS => None,
+                _ => arg(1).map(|x| (2, Some(u16::from_be_bytes([tag, x[0]]) as u32))),
+            }
+        } else {
+            match tag {
+                0x80..=0xFF => Some((1, Some(windows[active] + (tag - 0x80) as u32))),
+                _ if is_passthrough(tag as u32) => Some((1, Some(tag as u32))),
+                0x01..=0x08 => arg(1).map(|x| {
+                    let n = (tag - SQ0) as usize;
+                    (
+                        2,
+                        Some(if x[0] < 0x80 {
+                            STATIC_WINDOWS[n] + x[0] as u32
+                        } else {
+                            windows[n] + (x[0] - 0x80) as u32
+                        }),
+                    )
+                }),
+                SDX => arg(2).map(|x| {
+                    let word = u16::from_be_bytes([x[0], x[1]]) as u32;
+                    active = (word >> 13) as usize;
+                    windows[active] = 0x10000 + ((word & 0x1FFF) << 7);
+                    (3, None)
+                }),
+                SQU => arg(2).map(|x| (3, Some(u16::from_be_bytes([x[0], x[1]]) as u32))),
+                SCU => {
+                    unicode = true;
+                    Some((1, None))
+                }
+                0x10..=0x17 => {
+                    active = (tag - SC0) as usize;
+                    Some((1, None))
+                }
+                0x18..=0x1F => arg(1).and_then(|x| window_offset(x[0])).map(|off| {
+                    active = (tag - SD0) as usize;
+                    windows[active] = off;
+                    (2, None)
+                }),
+                _ => None,
+            }
+        };
+        let len = match step {
+            Some((len, cp)) => {
+                if let Some(cp) = cp {
+                    output.push((start, len, cp));
+                }
+                len
+            }
+            None => {
+                let truncated = start + 1 + argument_length(tag, unicode) > bytes.len();
+                let len = if truncated { bytes.len() - start } else { 1 };
+                output.push((start, len, REPLACEMENT));
+                errors.push(DecodeError {
+                    offset: start,
+                    bytes: bytes[start..start + len].to_vec(),
+                    truncated,
+                });
+                len
+            }
+        };
+        start += len;
+    }
+    let mut codepoints = Vec::new();
+    let mut iter = output.into_iter().peekable();
+    while let Some((offset, len, cp)) = iter.next() {
+        let low = match cp {
+            0xD800..=0xDBFF => iter.next_if(|(_, _, x)| (0xDC00..=0xDFFF).contains(x)),
+            _ => None,
+        };
+        match (cp, low) {
+            (_, Some((_, _, low))) => {
+                codepoints.push(0x10000 + ((cp - 0xD800) << 10) + (low - 0xDC00))
+            }
+            (0xD800..=0xDFFF, None) => {
+                codepoints.push(REPLACEMENT);
+                errors.push(DecodeError {
+                    offset,
+                    bytes: bytes[offset..offset + len].to_vec(),
+                    truncated: false,
+                });
+            }
+            _ => codepoints.push(cp),
+        }
+    }
+    errors.sort_by_key(|x| x.offset);
+    Decoded { codepoints, errors }
+}
@@ -0,0 +1,316 @@
+/*! Testing submodule for the codec module
+ */
+
+use super::*;
+use crate::testing::codepoints;
+
+#[test]
+fn decode_utf8_valid() {
+    let dec = decode(
+        &[0x61, 0xE2, 0x82, 0xAC, 0xF0, 0x9F, 0x98, 0x80],
+        &EncodingType::UTF8,
+    );
+    assert_eq!(dec.codepoints, vec![0x61, 0x20AC, 0x1F600]);
+    assert!(dec.errors.is_empty());
+}
+
+#[test]
+fn decode_utf8_invalid() {
+    let dec = decode(
+        &[0xE2, 0x82, 0xAC, 0xFF, 0x61, 0xF0, 0x9F],
+        &EncodingType::UTF8,
+    );
+    assert_eq!(dec.codepoints, vec![0x20AC, REPLACEMENT, 0x61, REPLACEMENT]);
+    assert_eq!(
+        dec.errors,
+        vec![
+            DecodeError {
+                offset: 3,
+                bytes: vec![0xFF],
+                truncated: false
+            },
+            DecodeError {
+                offset: 5,
+                bytes: vec![0xF0, 0x9F],
+                truncated: true
+            },
+        ]
+    );
+}
+
+#[test]
+fn decode_utf16() {
+    let dec = decode(&[0xD8, 0x3D, 0xDE, 0x00, 0x00, 0x61], &EncodingType::UTF16);
+    assert_eq!(dec.codepoints, vec![0x1F600, 0x61]);
+    let dec = decode(&[0x3D, 0xD8, 0x61, 0x00, 0x62], &EncodingType::UTF16LE);
+    assert_eq!(dec.codepoints, vec![REPLACEMENT, 0x61, REPLACEMENT]);
+    assert_eq!(
+        dec.errors,
+        vec![
+            DecodeError {
+                offset: 0,
+                bytes: vec![0x3D, 0xD8],
+                truncated: false
+            },
+            DecodeError {
+                offset: 4,
+                bytes: vec![0x62],
+                truncated: true
+            },
+        ]
+    );
+}
+
+#[test]
+fn decode_utf16_unit() {
+    assert_eq!(
+        decode_utf16_units(&[0xD83D, 0xDE00, 0xDE00, 0x61, 0xD83D]),
+        (vec![0x1F600, REPLACEMENT, 0x61, REPLACEMENT], vec![2, 4])
+    );
+}
+
+#[test]
+fn decode_utf32() {
+    let dec = decode(
+        &[0x00, 0x01, 0xF6, 0x00, 0x00, 0x11, 0x00, 0x00],
+        &EncodingType::UTF32,
+    );
+    assert_eq!(dec.codepoints, vec![0x1F600, REPLACEMENT]);
+    assert_eq!(dec.errors[0].offset, 4);
+    let dec = decode(&[0x61, 0x00, 0x00, 0x00, 0x62], &EncodingType::UTF32LE);
+    assert_eq!(dec.codepoints, vec![0x61, REPLACEMENT]);
+    assert!(dec.errors[0].truncated);
+}
+
+#[test]
+fn legacy_round_trip() {
+    // "\u{3042}\u{20AC}" in Shift_JIS
+    let dec = decode(
+        &[0x82, 0xA0, 0x80],
+        &EncodingType::Legacy(encoding_rs::SHIFT_JIS),
+    );
+    assert_eq!(dec.codepoints, vec![0x3042, 0x80]);
+    let enc = encode(
+        &[0x3042, 0x41],
+        &EncodingType::Legacy(encoding_rs::SHIFT_JIS),
+    );
+    assert_eq!(enc, Ok(vec![0x82, 0xA0, 0x41]));
+    let dec = decode(&[0x82, 0x20], &EncodingType::Legacy(encoding_rs::SHIFT_JIS));
+    assert_eq!(dec.errors[0].offset, 0);
+}
+
+#[test]
+fn encode_unrepresentable() {
+    let win = EncodingType::Legacy(encoding_rs::WINDOWS_1252);
+    assert_eq!(
+        encode(&[0x61, 0x3042, 0xE9, 0x1F600], &win),
+        Err(vec![1, 3])
+    );
+    assert_eq!(encode(&[0x61, 0x100], &EncodingType::Latin1), Err(vec![1]));
+    assert_eq!(encode(&[0xD800], &EncodingType::UTF8), Err(vec![0]));
+}
+
+#[test]
+fn encode_fallback() {
+    let win = EncodingType::Legacy(encoding_rs::WINDOWS_1252);
+    assert_eq!(
+        encode_with_fallback(&[0x61, 0x3042], &win, Fallback::Error),
+        Err(vec![1])
+    );
+    assert_eq!(
+        encode_with_fallback(&[0x61, 0x3042], &win, Fallback::Question),
+        Ok((b"a?".to_vec(), vec![1]))
+    );
+    assert_eq!(
+        encode_with_fallback(&[0x3042, 0xE9], &win, Fallback::NumericReference),
+        Ok((b"&#12354;\xE9".to_vec(), vec![0]))
+    );
+}
+
+#[test]
+fn utf7() {
+    let enc = |text| encode(&codepoints(text), &EncodingType::UTF7).unwrap();
+    assert_eq!(enc("Hi Mom -\u{263A}-!"), b"Hi Mom -+Jjo--+ACE-".to_vec());
+    assert_eq!(enc("\u{65E5}\u{672C}\u{8A9E}"), b"+ZeVnLIqe-".to_vec());
+    assert_eq!(enc("1+1"), b"1+-1".to_vec());
+    assert_eq!(enc("\u{1F600}"), b"+2D3eAA-".to_vec());
+    let dec = |text: &str| decode(text.as_bytes(), &EncodingType::UTF7);
+    assert_eq!(dec("A+ImIDkQ.").codepoints, codepoints("A\u{2262}\u{391}."));
+    assert_eq!(
+        dec("Hi Mom -+Jjo--!").codepoints,
+        codepoints("Hi Mom -\u{263A}-!")
+    );
+    assert_eq!(dec("1+-1").codepoints, codepoints("1+1"));
+    assert_eq!(dec("a+ZeV-").errors[0].offset, 1);
+    assert_eq!(dec("a+!").errors[0].offset, 1);
+}
+
+#[test]
+fn imap_utf7() {
+    let mailbox = "~peter/mail/\u{53F0}\u{5317}/\u{65E5}\u{672C}\u{8A9E}";
+    let encoded = b"~peter/mail/&U,BTFw-/&ZeVnLIqe-".to_vec();
+    assert_eq!(
+        encode(&codepoints(mailbox), &EncodingType::IMAPUTF7),
+        Ok(encoded.clone())
+    );
+    assert_eq!(
+        decode(&encoded, &EncodingType::IMAPUTF7).codepoints,
+        codepoints(mailbox)
+    );
+    assert_eq!(
+        encode(&codepoints("&"), &EncodingType::IMAPUTF7),
+        Ok(b"&-".to_vec())
+    );
+    // The base64 run must be terminated
+    assert!(!decode(b"&U,BTFw", &EncodingType::IMAPUTF7)
+        .errors
+        .is_empty());
+}
+
+#[test]
+fn scsu() {
+    let enc = |text| encode(&codepoints(text), &EncodingType::SCSU).unwrap();
+    // Samples from UTS #6
+    assert_eq!(
+        enc("\u{D6}l flie\u{DF}t"),
+        vec![0xD6, 0x6C, 0x20, 0x66, 0x6C, 0x69, 0x65, 0xDF, 0x74]
+    );
+    assert_eq!(
+        enc("\u{41C}\u{43E}\u{441}\u{43A}\u{432}\u{430}"),
+        vec![0x12, 0x9C, 0xBE, 0xC1, 0xBA, 0xB2, 0xB0]
+    );
+    assert_eq!(
+        enc("\u{65E5}\u{672C}a"),
+        vec![0x0F, 0x65, 0xE5, 0x67, 0x2C, 0xE0, 0x61]
+    );
+    assert_eq!(enc("\u{FEFF}"), vec![0x0E, 0xFE, 0xFF]);
+    let dec = |bytes: &[u8]| decode(bytes, &EncodingType::SCSU);
+    // SDX, SQU of a surrogate pair, SD1 with a fixed offset, UQU
+    assert_eq!(
+        dec(&[0x0B, 0x01, 0xEC, 0x80, 0x0E, 0xD8, 0x3D, 0x0E, 0xDE, 0x00]).codepoints,
+        vec![0x1F600, 0x1F600]
+    );
+    assert_eq!(dec(&[0x19, 0xF9, 0x80]).codepoints, vec![0xC0]);
+    assert_eq!(dec(&[0x0F, 0xF0, 0xE0, 0x00]).codepoints, vec![0xE000]);
+    assert_eq!(dec(&[0x61, 0x0C, 0x19]).errors.len(), 2);
+    assert!(dec(&[0x61, 0x19]).errors[0].truncated);
+}
+
+#[test]
+fn bocu1() {
+    let enc = |text| encode(&codepoints(text), &EncodingType::BOCU1).unwrap();
+    assert_eq!(enc("\u{FEFF}"), vec![0xFB, 0xEE, 0x28]);
+    assert_eq!(enc("a"), vec![0xB1]);
+    assert_eq!(enc("ab c"), vec![0xB1, 0xB2, 0x20, 0xB3]);
+    let dec = |bytes: &[u8]| decode(bytes, &EncodingType::BOCU1);
+    assert_eq!(dec(&[0xFB, 0xEE, 0x28]).codepoints, vec![0xFEFF]);
+    assert_eq!(dec(&[0xB1, 0xFF, 0xB1]).codepoints, vec![0x61, 0x61]);
+    assert!(dec(&[0xFB, 0xEE]).errors[0].truncated);
+    assert!(!dec(&[0xD0, 0x07]).errors[0].truncated);
+}
+
+#[test]
+fn utf32_byte_order() {
+    assert_eq!(bitsplitter(&[0x1F600], true), vec![0x00, 0x01, 0xF6, 0x00]);
+    assert_eq!(bitsplitter(&[0x1F600], false), vec![0x00, 0xF6, 0x01, 0x00]);
+    assert_eq!(
+        encode_file(&[0x61], &EncodingType::UTF32, Fallback::Error, true),
+        Ok((vec![0x00, 0x00, 0xFE, 0xFF, 0x00, 0x00, 0x00, 0x61], vec![]))
+    );
+    assert_eq!(
+        encode_file(&[0x61], &EncodingType::UTF32LE, Fallback::Error, true),
+        Ok((vec![0xFF, 0xFE, 0x00, 0x00, 0x61, 0x00, 0x00, 0x00], vec![]))
+    );
+}
+
+#[test]
+fn bom_mark() {
+    let ok = |enc, bom| encode_file(&[0x61], &enc, Fallback::Error, bom).unwrap().0;
+    assert_eq!(ok(EncodingType::UTF8, true), vec![0xEF, 0xBB, 0xBF, 0x61]);
+    assert_eq!(ok(EncodingType::UTF8, false), vec![0x61]);
+    assert_eq!(ok(EncodingType::UTF16, true), vec![0xFE, 0xFF, 0x00, 0x61]);
+    assert_eq!(
+        ok(EncodingType::UTF16LE, true),
+        vec![0xFF, 0xFE, 0x61, 0x00]
+    );
+    assert_eq!(ok(EncodingType::UTF16LE, false), vec![0x61, 0x00]);
+    assert_eq!(
+        encode_file(
+            &[0x61],
+            &EncodingType::Legacy(encoding_rs::WINDOWS_1252),
+            Fallback::Error,
+            true
+        ),
+        Err(EncodeError::NoBom)
+    );
+}
+
+#[test]
+fn decode_file_offset() {
+    let dec = decode_file(&[0xEF, 0xBB, 0xBF, 0x61, 0xFF], &EncodingType::UTF8);
+    assert_eq!(dec.codepoints, vec![0x61, REPLACEMENT]);
+    assert_eq!(dec.errors[0].offset, 4);
+}
+
+/// Every unicode encoding, followed by a sample of the legacy encodings
+fn encodings() -> Vec<EncodingType> {
+    vec![
+        EncodingType::UTF8,
+        EncodingType::UTF16,
+        EncodingType::UTF16LE,
+        EncodingType::UTF32,
+        EncodingType::UTF32LE,
+        EncodingType::UTF7,
+        EncodingType::IMAPUTF7,
+        EncodingType::SCSU,
+        EncodingType::BOCU1,
+        EncodingType::Latin1,
+        EncodingType::Legacy(encoding_rs::WINDOWS_1252),
+        EncodingType::Legacy(encoding_rs::WINDOWS_1251),
+        EncodingType::Legacy(encoding_rs::ISO_8859_2),
+        EncodingType::Legacy(encoding_rs::KOI8_R),
+        EncodingType::Legacy(encoding_rs::SHIFT_JIS),
+        EncodingType::Legacy(encoding_rs::EUC_JP),
+        EncodingType::Legacy(encoding_rs::EUC_KR),
+        EncodingType::Legacy(encoding_rs::GB18030),
+        EncodingType::Legacy(encoding_rs::BIG5),
+    ]
+}
+
+proptest::proptest! {
+    #[test]
+    fn round_trip_unicode(text in "\\PC*", bom: bool) {
+        let buff = codepoints(&text);
+        // A leading U+FEFF is indistinguishable from the byte order mark
+        proptest::prop_assume!(buff.first() != Some(&BOM));
+        for enc in encodings().into_iter().take(9) {
+            let (bytes, pos) = encode_file(&buff, &enc, Fallback::Error, bom).unwrap();
+            proptest::prop_assert!(pos.is_empty());
+            let dec = decode_file(&bytes, &enc);
+            proptest::prop_assert!(dec.errors.is_empty());
+            proptest::prop_assert_eq!(&dec.codepoints, &buff, "{:?}", enc);
+        }
+    }
+
+    #[test]
+    fn round_trip_legacy(sample in proptest::collection::vec(proptest::num::u8::ANY, 0..64), bom: bool) {
+        for enc in encodings().into_iter().skip(9) {
+            // Obtain a buffer which is representable in the encoding
+            let buff: Vec<u32> = decode(&sample, &enc)
+                .codepoints
+                .into_iter()
+                .filter(|&x| x != BOM && encode(&[x], &enc).is_ok())
+                .collect();
+            let bytes = match encode_file(&buff, &enc, Fallback::Error, bom) {
+                Ok((bytes, _)) => bytes,
+                Err(err) => {
+                    proptest::prop_assert_eq!(err, EncodeError::NoBom);
+                    continue;
+                }
+            };
+            let dec = decode_file(&bytes, &enc);
+            proptest::prop_assert!(dec.errors.is_empty());
+            proptest::prop_assert_eq!(&dec.codepoints, &buff, "{:?}", enc);
+        }
+    }
+}
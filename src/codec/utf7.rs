@@ -0,0 +1,162 @@
+/*! UTF-7 as of RFC 2152, and the IMAP modified UTF-7 as of RFC 3501
+
+Both encode the codepoints outside of their direct character set as the UTF-16
+code units written in a base64 run. They differ in the shift character
+(`+` or `&`), the base64 alphabet (`/` or `,`) and the direct character set.
+The encoder always terminates the base64 run with `-`,
+while the decoder of UTF-7 also accepts the implicit termination.
+ */
+
+use super::{decode_utf16_units, DecodeError, Decoded};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn shift(imap: bool) -> u8 {
+    if imap {
+        b'&'
+    } else {
+        b'+'
+    }
+}
+
+fn alphabet(val: u8, imap: bool) -> u8 {
+    match BASE64[val as usize] {
+        b'/' if imap => b',',
+        x => x,
+    }
+}
+
+fn alphabet_value(chr: u8, imap: bool) -> Option<u8> {
+    match chr {
+        b'A'..=b'Z' => Some(chr - b'A'),
+        b'a'..=b'z' => Some(chr - b'a' + 26),
+        b'0'..=b'9' => Some(chr - b'0' + 52),
+        b'+' => Some(62),
+        b',' if imap => Some(63),
+        b'/' if !imap => Some(63),
+        _ => None,
+    }
+}
+
+/// Whether the character is written as itself
+fn is_direct(chr: char, imap: bool) -> bool {
+    if imap {
+        (' '..='~').contains(&chr) && chr != '&'
+    } else {
+        chr.is_ascii_alphanumeric() || "'(),-./:? \t\r\n".contains(chr)
+    }
+}
+
+pub fn encode(text: &str, imap: bool) -> Vec<u8> {
+    let text: Vec<char> = text.chars().collect();
+    let mut bytes = Vec::new();
+    let mut idx = 0;
+    while idx < text.len() {
+        let chr = text[idx];
+        if is_direct(chr, imap) {
+            bytes.push(chr as u8);
+            idx += 1;
+            continue;
+        }
+        if chr as u32 == shift(imap) as u32 {
+            bytes.extend([shift(imap), b'-']);
+            idx += 1;
+            continue;
+        }
+        let end = text[idx..]
+            .iter()
+            .position(|&x| is_direct(x, imap) || x as u32 == shift(imap) as u32)
+            .map_or(text.len(), |x| idx + x);
+        let units: Vec<u16> = text[idx..end]
+            .iter()
+            .flat_map(|x| {
+                let mut buf = [0; 2];
+                x.encode_utf16(&mut buf).to_vec()
+            })
+            .collect();
+        bytes.push(shift(imap));
+        let (mut bits, mut count) = (0u32, 0);
+        for unit in units {
+            bits = (bits << 16) | unit as u32;
+            count += 16;
+            while count >= 6 {
+                count -= 6;
+                bytes.push(alphabet(((bits >> count) & 0x3F) as u8, imap));
+            }
+        }
+        if count > 0 {
+            bytes.push(alphabet(((bits << (6 - count)) & 0x3F) as u8, imap));
+        }
+        bytes.push(b'-');
+        idx = end;
+    }
+    bytes
+}
+
+pub fn decode(bytes: &[u8], imap: bool) -> Decoded {
+    let mut codepoints = Vec::new();
+    let mut errors = Vec::new();
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let chr = bytes[idx];
+        if chr != shift(imap) {
+            if chr.is_ascii() && (!imap || (0x20..0x7F).contains(&chr)) {
+                codepoints.push(chr as u32);
+            } else {
+                codepoints.push(super::REPLACEMENT);
+                errors.push(DecodeError {
+                    offset: idx,
+                    bytes: vec![chr],
+                    truncated: false,
+                });
+            }
+            idx += 1;
+            continue;
+        }
+        let start = idx;
+        idx += 1;
+        if bytes.get(idx) == Some(&b'-') {
+            codepoints.push(chr as u32);
+            idx += 1;
+            continue;
+        }
+        let (mut bits, mut count) = (0u32, 0);
+        let mut units = Vec::new();
+        while let Some(val) = bytes.get(idx).and_then(|&x| alphabet_value(x, imap)) {
+            bits = (bits << 6) | val as u32;
+            count += 6;
+            if count >= 16 {
+                count -= 16;
+                units.push((bits >> count) as u16);
+            }
+            idx += 1;
+        }
+        let terminated = bytes.get(idx) == Some(&b'-');
+        let valid = !units.is_empty()
+            && count < 6
+            && bits & ((1 << count) - 1) == 0
+            && (terminated || !imap);
+        if terminated {
+            idx += 1;
+        }
+        if !valid {
+            codepoints.push(super::REPLACEMENT);
+            errors.push(DecodeError {
+                offset: start,
+                bytes: bytes[start..idx].to_vec(),
+                truncated: idx == bytes.len() && !terminated,
+            });
+            continue;
+        }
+        let (mut decoded, unpaired) = decode_utf16_units(&units);
+        if !unpaired.is_empty() {
+            errors.push(DecodeError {
+                offset: start,
+                bytes: bytes[start..idx].to_vec(),
+                truncated: false,
+            });
+        }
+        codepoints.append(&mut decoded);
+    }
+    Decoded { codepoints, errors }
+}
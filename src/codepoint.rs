@@ -0,0 +1,117 @@
+/*! Helpers to present a single codepoint to the user
+ */
+
+#[cfg(test)]
+mod testing;
+
+/// Whether the codepoint is within one of the sorted ranges of the table
+pub fn in_ranges(table: &[(u32, u32)], cp: u32) -> bool {
+    table
+        .binary_search_by(|&(first, last)| {
+            if last < cp {
+                std::cmp::Ordering::Less
+            } else if first > cp {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Format the codepoint in the `U+XXXX` notation
+pub fn notation(cp: u32) -> String {
+    format!("U+{:04X}", cp)
+}
+
+/// Format the codepoint at the position of the buffer, or mark the end of the buffer
+pub fn notation_at(buff: &[u32], pos: usize) -> String {
+    match buff.get(pos) {
+        Some(&cp) => notation(cp),
+        None => "<end>".to_string(),
+    }
+}
+
+/// Obtain the unicode character name of the codepoint, or a placeholder for the unnamed one
+pub fn name(cp: u32) -> String {
+    match char::from_u32(cp) {
+        None => "<invalid>".to_string(),
+        Some(chr) => match unicode_names2::name(chr) {
+            Some(nm) => nm.to_string(),
+            None if chr.is_control() => "<control>".to_string(),
+            None => "<unnamed>".to_string(),
+        },
+    }
+}
+
+/// Format the codepoint in the `U+XXXX` notation followed by its name
+pub fn describe(cp: u32) -> String {
+    format!("{} {}", notation(cp), name(cp))
+}
+
+/** Obtain a printable representation of the codepoint
+
+Control characters and invalid codepoints are not printed, and the combining marks
+are printed on top of a dotted circle so they do not combine with the surrounding text.
+ */
+pub fn glyph(cp: u32) -> String {
+    match char::from_u32(cp) {
+        None => " ".to_string(),
+        Some(chr) if chr.is_control() => " ".to_string(),
+        Some(chr) if unicode_normalization::char::is_combining_mark(chr) => {
+            format!("\u{25CC}{}", chr)
+        }
+        Some(chr) => chr.to_string(),
+    }
+}
+
+/// Format the UTF-8 bytes of the codepoint as hexadecimal, or `-` when it cannot be encoded
+pub fn utf8_bytes(cp: u32) -> String {
+    match char::from_u32(cp) {
+        None => "-".to_string(),
+        Some(chr) => chr
+            .encode_utf8(&mut [0; 4])
+            .bytes()
+            .map(|x| format!("{:02X}", x))
+            .collect::<Vec<String>>()
+            .join(" "),
+    }
+}
+
+/** Format the bits of the UTF-8 bytes of the codepoint
+
+The marker bits of the lead and continuation bytes are separated from the payload bits
+by a `|`, e.g. `110|00011 10|101001` for `U+00E9`.
+ */
+pub fn utf8_bits(cp: u32) -> String {
+    match char::from_u32(cp) {
+        None => "-".to_string(),
+        Some(chr) => chr
+            .encode_utf8(&mut [0; 4])
+            .bytes()
+            .map(|x| {
+                let marker = if x < 0x80 {
+                    1
+                } else {
+                    x.leading_ones() as usize + 1
+                };
+                let bits = format!("{:08b}", x);
+                format!("{}|{}", &bits[..marker], &bits[marker..])
+            })
+            .collect::<Vec<String>>()
+            .join(" "),
+    }
+}
+
+/// Format the UTF-16 Big Endian bytes of the codepoint as hexadecimal, or `-` when it cannot be encoded
+pub fn utf16_bytes(cp: u32) -> String {
+    match char::from_u32(cp) {
+        None => "-".to_string(),
+        Some(chr) => chr
+            .encode_utf16(&mut [0; 2])
+            .iter()
+            .map(|x| format!("{:02X} {:02X}", x >> 8, x & 0xff))
+            .collect::<Vec<String>>()
+            .join(" "),
+    }
+}
@@ -0,0 +1,67 @@
+/*! Testing submodule for the codepoint module
+ */
+
+use super::*;
+
+#[test]
+fn notations() {
+    assert_eq!(notation(0x41), "U+0041");
+    assert_eq!(notation(0x1F600), "U+1F600");
+    assert_eq!(notation_at(&[0x41], 0), "U+0041");
+    assert_eq!(notation_at(&[0x41], 1), "<end>");
+    assert_eq!(name(0x41), "LATIN CAPITAL LETTER A");
+    assert_eq!(name(0x07), "<control>");
+    assert_eq!(name(0xD800), "<invalid>");
+    assert_eq!(describe(0xE9), "U+00E9 LATIN SMALL LETTER E WITH ACUTE");
+}
+#[test]
+fn glyphs() {
+    assert_eq!(glyph(0x41), "A");
+    assert_eq!(glyph(0x20AC), "€");
+    assert_eq!(glyph(0x0301), "\u{25CC}\u{0301}");
+    assert_eq!(glyph(0x0A), " ");
+    assert_eq!(glyph(0xD800), " ");
+    assert_eq!(glyph(0x110000), " ");
+}
+#[test]
+fn utf8_hex() {
+    assert_eq!(utf8_bytes(0x41), "41");
+    assert_eq!(utf8_bytes(0xE9), "C3 A9");
+    assert_eq!(utf8_bytes(0x20AC), "E2 82 AC");
+    assert_eq!(utf8_bytes(0x1F600), "F0 9F 98 80");
+    assert_eq!(utf8_bytes(0xD800), "-");
+    assert_eq!(utf8_bytes(0x110000), "-");
+}
+#[test]
+fn utf8_binary() {
+    assert_eq!(utf8_bits(0x41), "0|1000001");
+    assert_eq!(utf8_bits(0xE9), "110|00011 10|101001");
+    assert_eq!(utf8_bits(0x20AC), "1110|0010 10|000010 10|101100");
+    assert_eq!(
+        utf8_bits(0x1F600),
+        "11110|000 10|011111 10|011000 10|000000"
+    );
+    assert_eq!(utf8_bits(0xDFFF), "-");
+    assert_eq!(utf8_bits(0x110000), "-");
+}
+#[test]
+fn utf16_hex() {
+    assert_eq!(utf16_bytes(0x41), "00 41");
+    assert_eq!(utf16_bytes(0xE9), "00 E9");
+    assert_eq!(utf16_bytes(0x20AC), "20 AC");
+    assert_eq!(utf16_bytes(0x1F600), "D8 3D DE 00");
+    assert_eq!(utf16_bytes(0xD800), "-");
+    assert_eq!(utf16_bytes(0x110000), "-");
+}
+#[test]
+fn ranges() {
+    let table = [(0x41, 0x5A), (0x61, 0x61), (0x100, 0x17F)];
+    assert!(in_ranges(&table, 0x41));
+    assert!(in_ranges(&table, 0x5A));
+    assert!(in_ranges(&table, 0x61));
+    assert!(in_ranges(&table, 0x120));
+    assert!(!in_ranges(&table, 0x40));
+    assert!(!in_ranges(&table, 0x62));
+    assert!(!in_ranges(&table, 0x180));
+    assert!(!in_ranges(&[], 0x41));
+}
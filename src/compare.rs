@@ -0,0 +1,61 @@
+/*! Equivalence comparison between two codepoint sequences
+ */
+
+#[cfg(test)]
+mod testing;
+
+use unicode_normalization::UnicodeNormalization;
+
+/** Result of the comparison of two sequences
+
+The normalization and case insensitive equivalences are only available
+when both sequences consist of valid unicode scalar values.
+ */
+#[derive(Debug, Eq, PartialEq)]
+pub struct Comparison {
+    /// Both sequences have exactly the same codepoints
+    pub binary: bool,
+    /// Position of the first differing codepoint, if any
+    pub first_diff: Option<usize>,
+    /// Equivalences under each normalization form, and case insensitivity
+    pub forms: Option<Equivalence>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Equivalence {
+    pub nfc: bool,
+    pub nfd: bool,
+    pub nfkc: bool,
+    pub nfkd: bool,
+    /// Canonical caseless match, as defined by the unicode standard D145
+    pub caseless: bool,
+}
+
+pub fn compare(left: &[u32], right: &[u32]) -> Comparison {
+    let first_diff = left
+        .iter()
+        .zip(right.iter())
+        .position(|(l, r)| l != r)
+        .or(if left.len() == right.len() {
+            None
+        } else {
+            Some(left.len().min(right.len()))
+        });
+    Comparison {
+        binary: first_diff.is_none(),
+        first_diff,
+        forms: to_string(left)
+            .zip(to_string(right))
+            .map(|(l, r)| Equivalence {
+                nfc: l.nfc().eq(r.nfc()),
+                nfd: l.nfd().eq(r.nfd()),
+                nfkc: l.nfkc().eq(r.nfkc()),
+                nfkd: l.nfkd().eq(r.nfkd()),
+                caseless: caseless::canonical_caseless_match_str(&l, &r),
+            }),
+    }
+}
+
+fn to_string(buff: &[u32]) -> Option<String> {
+    buff.iter().map(|&x| char::from_u32(x)).collect()
+}
@@ -0,0 +1,49 @@
+/*! Testing submodule for the compare module
+ */
+
+use super::*;
+
+#[test]
+fn compare_binary() {
+    let cmp = compare(&[0x61, 0x62], &[0x61, 0x62]);
+    assert!(cmp.binary);
+    assert_eq!(cmp.first_diff, None);
+    assert_eq!(compare(&[0x61, 0x62], &[0x61, 0x63]).first_diff, Some(1));
+    assert_eq!(compare(&[0x61], &[0x61, 0x63]).first_diff, Some(1));
+    assert_eq!(compare(&[0x61, 0x63], &[]).first_diff, Some(0));
+}
+
+#[test]
+fn compare_canonical() {
+    // U+00E9 against U+0065 U+0301
+    let forms = compare(&[0xE9], &[0x65, 0x301]).forms.unwrap();
+    assert!(forms.nfc && forms.nfd && forms.nfkc && forms.nfkd && forms.caseless);
+}
+
+#[test]
+fn compare_compatibility() {
+    // U+FB01 LATIN SMALL LIGATURE FI against "fi"
+    let forms = compare(&[0xFB01], &[0x66, 0x69]).forms.unwrap();
+    assert!(!forms.nfc && !forms.nfd);
+    assert!(forms.nfkc && forms.nfkd);
+}
+
+#[test]
+fn compare_caseless() {
+    // "Straße" against "STRASSE"
+    let forms = compare(
+        &[0x53, 0x74, 0x72, 0x61, 0xDF, 0x65],
+        &[0x53, 0x54, 0x52, 0x41, 0x53, 0x53, 0x45],
+    )
+    .forms
+    .unwrap();
+    assert!(!forms.nfc && !forms.nfkc);
+    assert!(forms.caseless);
+}
+
+#[test]
+fn compare_invalid() {
+    let cmp = compare(&[0xD800], &[0xD800]);
+    assert!(cmp.binary);
+    assert_eq!(cmp.forms, None);
+}
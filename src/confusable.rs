@@ -0,0 +1,199 @@
+/*! Confusable detection following UTS #39
+
+Two strings are confusable when they share the same skeleton, which is obtained by mapping
+each character to its prototype from `confusables.txt`, between two canonical decompositions.
+A single-script string is whole-script confusable when each of its letters can be replaced
+by a confusable letter of another script, and a mixed-script string is mixed-script confusable
+when its letters can all be replaced by the letters of one of its scripts.
+The replacements are looked up character by character, among the characters allowed
+in the identifiers by the General Security Profile.
+ */
+
+#[cfg(test)]
+mod testing;
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{Script, ScriptExtension, UnicodeScript};
+use unicode_security::{GeneralSecurityProfile, MixedScript};
+
+pub use unicode_security::RestrictionLevel;
+use unicode_security::RestrictionLevelDetection;
+
+/// Replacement of a string by the confusable characters of a single script
+#[derive(Debug, PartialEq, Eq)]
+pub struct Substitution {
+    pub script: Script,
+    pub text: Vec<char>,
+    /// Positions of the replaced characters
+    pub positions: Vec<usize>,
+}
+
+/// Skeleton of the text
+pub fn skeleton(text: &[char]) -> Vec<char> {
+    let text: String = text.iter().collect();
+    unicode_security::skeleton(&text).collect()
+}
+
+/// Skeleton of each character of the text
+fn pieces(text: &[char]) -> Vec<String> {
+    text.iter()
+        .map(|&chr| unicode_security::skeleton(&chr.to_string()).collect())
+        .collect()
+}
+
+/// Positions of the characters which differ from their skeleton, together with the skeleton
+pub fn mapped(text: &[char]) -> Vec<(usize, String)> {
+    pieces(text)
+        .into_iter()
+        .enumerate()
+        .filter(|(pos, piece)| *piece != text[*pos].to_string().nfd().collect::<String>())
+        .collect()
+}
+
+/// Position of the character holding the skeleton character at `idx`
+fn owner(pieces: &[String], idx: usize) -> usize {
+    let mut total = 0;
+    for (pos, piece) in pieces.iter().enumerate() {
+        total += piece.chars().count();
+        if idx < total {
+            return pos;
+        }
+    }
+    pieces.len()
+}
+
+/** Positions of the two texts from which their skeletons differ
+
+Return None when the two texts are confusable.
+ */
+pub fn first_difference(left: &[char], right: &[char]) -> Option<(usize, usize)> {
+    if skeleton(left) == skeleton(right) {
+        return None;
+    }
+    let (left_pieces, right_pieces) = (pieces(left), pieces(right));
+    let left_skel: Vec<char> = left_pieces.iter().flat_map(|x| x.chars()).collect();
+    let right_skel: Vec<char> = right_pieces.iter().flat_map(|x| x.chars()).collect();
+    let idx = left_skel
+        .iter()
+        .zip(&right_skel)
+        .take_while(|(a, b)| a == b)
+        .count();
+    Some((owner(&left_pieces, idx), owner(&right_pieces, idx)))
+}
+
+fn is_neutral(ext: ScriptExtension) -> bool {
+    ext.is_common() || ext.is_inherited()
+}
+
+/// Positions of the characters of each script, the Common and Inherited characters being skipped
+pub fn scripts(text: &[char]) -> Vec<(Script, Vec<usize>)> {
+    let mut out: Vec<(Script, Vec<usize>)> = Vec::new();
+    for (pos, chr) in text.iter().enumerate() {
+        let ext = chr.script_extension();
+        if is_neutral(ext) {
+            continue;
+        }
+        for script in ext.iter() {
+            match out.iter_mut().find(|x| x.0 == script) {
+                Some(entry) => entry.1.push(pos),
+                None => out.push((script, vec![pos])),
+            }
+        }
+    }
+    out
+}
+
+/// Whether no script is shared by all of the characters of the text
+pub fn is_mixed(text: &[char]) -> bool {
+    let text: String = text.iter().collect();
+    !text.as_str().is_single_script()
+}
+
+/// Restriction level of the text, as an identifier
+pub fn restriction(text: &[char]) -> RestrictionLevel {
+    let text: String = text.iter().collect();
+    text.as_str().detect_restriction_level()
+}
+
+/// Characters allowed in the identifiers, grouped by their skeleton, built on the first use
+fn prototypes() -> &'static HashMap<String, Vec<char>> {
+    static PROTOTYPES: OnceLock<HashMap<String, Vec<char>>> = OnceLock::new();
+    PROTOTYPES.get_or_init(|| {
+        let mut out: HashMap<String, Vec<char>> = HashMap::new();
+        for chr in (0..=0x10FFFF).filter_map(char::from_u32) {
+            if !chr.identifier_allowed() || is_neutral(chr.script_extension()) {
+                continue;
+            }
+            let piece: String = unicode_security::skeleton(&chr.to_string()).collect();
+            out.entry(piece).or_default().push(chr);
+        }
+        out
+    })
+}
+
+/// Replace the characters which are not in the script, return None when one of them has no replacement
+fn substitute(
+    text: &[char],
+    script: Script,
+    index: &HashMap<String, Vec<char>>,
+) -> Option<Substitution> {
+    let mut out = Vec::new();
+    let mut positions = Vec::new();
+    for (pos, (&chr, piece)) in text.iter().zip(pieces(text)).enumerate() {
+        let ext = chr.script_extension();
+        if is_neutral(ext) || ext.contains_script(script) {
+            out.push(chr);
+            continue;
+        }
+        let found = index
+            .get(&piece)?
+            .iter()
+            .find(|x| x.script_extension().contains_script(script))?;
+        out.push(*found);
+        positions.push(pos);
+    }
+    Some(Substitution {
+        script,
+        text: out,
+        positions,
+    })
+}
+
+/// Confusable strings of the single-script text in each of the other scripts
+pub fn whole_script(text: &[char]) -> Vec<Substitution> {
+    if is_mixed(text) {
+        return Vec::new();
+    }
+    let own = scripts(text);
+    let Some(&first) = own.first().and_then(|x| x.1.first()).map(|&pos| &text[pos]) else {
+        return Vec::new();
+    };
+    let index = prototypes();
+    let piece: String = unicode_security::skeleton(&first.to_string()).collect();
+    let mut candidates: Vec<Script> = Vec::new();
+    for other in index.get(&piece).into_iter().flatten() {
+        for script in other.script_extension().iter() {
+            if !candidates.contains(&script) && !own.iter().any(|x| x.0 == script) {
+                candidates.push(script);
+            }
+        }
+    }
+    candidates
+        .into_iter()
+        .filter_map(|script| substitute(text, script, index))
+        .collect()
+}
+
+/// Single-script strings confusable with the mixed-script text, in each of its scripts
+pub fn mixed_script(text: &[char]) -> Vec<Substitution> {
+    if !is_mixed(text) {
+        return Vec::new();
+    }
+    let index = prototypes();
+    scripts(text)
+        .into_iter()
+        .filter_map(|(script, _)| substitute(text, script, index))
+        .collect()
+}
@@ -0,0 +1,78 @@
+/*! Testing submodule for the confusable module
+ */
+
+use super::*;
+
+fn chars(text: &str) -> Vec<char> {
+    text.chars().collect()
+}
+
+#[test]
+fn skeletons() {
+    // The second letter is the Cyrillic a
+    let spoof = chars("p\u{0430}ypal");
+    assert_eq!(skeleton(&spoof), skeleton(&chars("paypal")));
+    assert_eq!(mapped(&spoof), vec![(1, "a".to_string())]);
+    assert_eq!(mapped(&chars("paypal")), vec![]);
+}
+
+#[test]
+fn compare() {
+    assert_eq!(first_difference(&chars("paypal"), &chars("paypa1")), None);
+    assert_eq!(
+        first_difference(&chars("apple"), &chars("appie")),
+        Some((3, 3))
+    );
+    // The m is confusable with r followed by n
+    assert_eq!(first_difference(&chars("modern"), &chars("rnodern")), None);
+    assert_eq!(
+        first_difference(&chars("modern"), &chars("rnodenn")),
+        Some((4, 5))
+    );
+}
+
+#[test]
+fn mixed() {
+    let spoof = chars("p\u{0430}ypal");
+    assert!(is_mixed(&spoof));
+    assert!(!is_mixed(&chars("paypal 1")));
+    assert_eq!(
+        scripts(&spoof),
+        vec![
+            (Script::Latin, vec![0, 2, 3, 4, 5]),
+            (Script::Cyrillic, vec![1])
+        ]
+    );
+    assert_eq!(restriction(&chars("paypal")), RestrictionLevel::ASCIIOnly);
+    assert_eq!(restriction(&spoof), RestrictionLevel::MinimallyRestrictive);
+    assert_eq!(
+        mixed_script(&spoof),
+        vec![
+            Substitution {
+                script: Script::Latin,
+                text: chars("paypal"),
+                positions: vec![1]
+            },
+            // The l is confusable with the Cyrillic capital letter І
+            Substitution {
+                script: Script::Cyrillic,
+                text: chars("\u{0440}\u{0430}\u{0443}\u{0440}\u{0430}\u{0406}"),
+                positions: vec![0, 2, 3, 4, 5]
+            }
+        ]
+    );
+}
+
+#[test]
+fn whole() {
+    let found = whole_script(&chars("\u{0455}\u{0441}\u{043E}\u{0440}\u{0435}"));
+    assert_eq!(
+        found.iter().find(|x| x.script == Script::Latin),
+        Some(&Substitution {
+            script: Script::Latin,
+            text: chars("scope"),
+            positions: vec![0, 1, 2, 3, 4]
+        })
+    );
+    assert_eq!(whole_script(&chars("p\u{0430}ypal")), vec![]);
+}
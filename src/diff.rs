@@ -0,0 +1,100 @@
+/*! Codepoint level difference between two sequences
+
+The difference is computed from the longest common subsequence of both sequences.
+ */
+
+#[cfg(test)]
+mod testing;
+
+/** Single step of the difference
+
+The positions are the index of the codepoint in the left and right sequence.
+ */
+#[derive(Debug, Eq, PartialEq)]
+pub enum Edit {
+    /// Codepoint present in both sequences
+    Keep { left: usize, right: usize, cp: u32 },
+    /// Codepoint only present in the right sequence
+    Insert { right: usize, cp: u32 },
+    /// Codepoint only present in the left sequence
+    Delete { left: usize, cp: u32 },
+    /// Codepoint of the left sequence replaced by a codepoint of the right sequence
+    Change {
+        left: usize,
+        right: usize,
+        from: u32,
+        to: u32,
+    },
+}
+
+pub fn diff(left: &[u32], right: &[u32]) -> Vec<Edit> {
+    // lcs[i][j] is the length of the LCS of left[i..] and right[j..]
+    let mut lcs = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            lcs[i][j] = if left[i] == right[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let mut deleted: Vec<usize> = Vec::new();
+    let mut inserted: Vec<usize> = Vec::new();
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            flush(left, right, &mut deleted, &mut inserted, &mut edits);
+            edits.push(Edit::Keep {
+                left: i,
+                right: j,
+                cp: left[i],
+            });
+            i += 1;
+            j += 1;
+        } else if j == right.len() || (i < left.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            deleted.push(i);
+            i += 1;
+        } else {
+            inserted.push(j);
+            j += 1;
+        }
+    }
+    flush(left, right, &mut deleted, &mut inserted, &mut edits);
+    edits
+}
+
+/// Pair the pending deletions and insertions as changes, and push them to the edits
+fn flush(
+    left: &[u32],
+    right: &[u32],
+    deleted: &mut Vec<usize>,
+    inserted: &mut Vec<usize>,
+    edits: &mut Vec<Edit>,
+) {
+    let paired = deleted.len().min(inserted.len());
+    for (&l, &r) in deleted.iter().zip(inserted.iter()) {
+        edits.push(Edit::Change {
+            left: l,
+            right: r,
+            from: left[l],
+            to: right[r],
+        });
+    }
+    for &l in &deleted[paired..] {
+        edits.push(Edit::Delete {
+            left: l,
+            cp: left[l],
+        });
+    }
+    for &r in &inserted[paired..] {
+        edits.push(Edit::Insert {
+            right: r,
+            cp: right[r],
+        });
+    }
+    deleted.clear();
+    inserted.clear();
+}
@@ -0,0 +1,69 @@
+/*! Testing submodule for the diff module
+ */
+
+use super::*;
+
+#[test]
+fn diff_equal() {
+    assert_eq!(
+        diff(&[0x61, 0x62], &[0x61, 0x62]),
+        vec![
+            Edit::Keep {
+                left: 0,
+                right: 0,
+                cp: 0x61
+            },
+            Edit::Keep {
+                left: 1,
+                right: 1,
+                cp: 0x62
+            },
+        ]
+    );
+    assert_eq!(diff(&[], &[]), vec![]);
+}
+
+#[test]
+fn diff_decomposition() {
+    // "caf\u{E9}" against "cafe\u{301}"
+    let edits = diff(&[0x63, 0x61, 0x66, 0xE9], &[0x63, 0x61, 0x66, 0x65, 0x301]);
+    assert_eq!(
+        edits[3..],
+        [
+            Edit::Change {
+                left: 3,
+                right: 3,
+                from: 0xE9,
+                to: 0x65
+            },
+            Edit::Insert {
+                right: 4,
+                cp: 0x301
+            },
+        ]
+    );
+}
+
+#[test]
+fn diff_insert_delete() {
+    assert_eq!(
+        diff(&[0x61, 0x62, 0x63], &[0x61, 0x63]),
+        vec![
+            Edit::Keep {
+                left: 0,
+                right: 0,
+                cp: 0x61
+            },
+            Edit::Delete { left: 1, cp: 0x62 },
+            Edit::Keep {
+                left: 2,
+                right: 1,
+                cp: 0x63
+            },
+        ]
+    );
+    assert_eq!(
+        diff(&[], &[0x61]),
+        vec![Edit::Insert { right: 0, cp: 0x61 }]
+    );
+}
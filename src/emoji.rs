@@ -0,0 +1,248 @@
+/*! Building and validation of the emoji sequences
+
+The sequences are checked against the RGI emoji set of UTS #51, which is the set of sequences
+recommended for general interchange: the basic emoji, the keycaps, the flags of the regions and
+of the subdivisions, the skin tone modifier sequences, and the ZWJ sequences.
+ */
+
+mod tables;
+#[cfg(test)]
+mod testing;
+
+use crate::cmd::SkinTone;
+use crate::codepoint::in_ranges;
+
+pub const ZWJ: u32 = 0x200D;
+/// Variation selector requesting the emoji presentation
+pub const VS16: u32 = 0xFE0F;
+pub const KEYCAP: u32 = 0x20E3;
+const KEYCAP_BASES: &str = "0123456789#*";
+const REGIONAL_A: u32 = 0x1F1E6;
+const REGIONAL_Z: u32 = 0x1F1FF;
+/// Base of the subdivision flags, followed by the tags and the cancel tag
+const BLACK_FLAG: u32 = 0x1F3F4;
+const TAG_BASE: u32 = 0xE0000;
+const CANCEL_TAG: u32 = 0xE007F;
+const MODIFIER_FIRST: u32 = 0x1F3FB;
+const MODIFIER_LAST: u32 = 0x1F3FF;
+
+/// Kind of the RGI emoji sequence
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Kind {
+    Basic,
+    Keycap,
+    Flag,
+    Subdivision,
+    Modifier,
+    Zwj,
+}
+
+impl Kind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Kind::Basic => "basic emoji",
+            Kind::Keycap => "keycap",
+            Kind::Flag => "flag",
+            Kind::Subdivision => "subdivision flag",
+            Kind::Modifier => "modifier sequence",
+            Kind::Zwj => "ZWJ sequence",
+        }
+    }
+}
+
+/// Emoji sequence found in the buffer, from `start` up to `end` excluded
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sequence {
+    pub start: usize,
+    pub end: usize,
+    /// None when the sequence is not in the RGI set
+    pub kind: Option<Kind>,
+}
+
+fn is_modifier(cp: u32) -> bool {
+    (MODIFIER_FIRST..=MODIFIER_LAST).contains(&cp)
+}
+
+fn is_regional(cp: u32) -> bool {
+    (REGIONAL_A..=REGIONAL_Z).contains(&cp)
+}
+
+fn is_tag(cp: u32) -> bool {
+    (TAG_BASE + 0x20..=CANCEL_TAG).contains(&cp)
+}
+
+fn is_keycap_base(cp: u32) -> bool {
+    char::from_u32(cp).is_some_and(|x| KEYCAP_BASES.contains(x))
+}
+
+/// Skin tone modifier codepoint
+pub fn tone(tone: &SkinTone) -> u32 {
+    MODIFIER_FIRST
+        + match tone {
+            SkinTone::Light => 0,
+            SkinTone::MediumLight => 1,
+            SkinTone::Medium => 2,
+            SkinTone::MediumDark => 3,
+            SkinTone::Dark => 4,
+        }
+}
+
+/// Whether the codepoint takes a skin tone modifier
+pub fn is_modifier_base(cp: u32) -> bool {
+    in_ranges(&tables::MODIFIER_BASES, cp)
+}
+
+/// Skin tone modifier sequence, the emoji presentation selector of the base being dropped
+pub fn modifier(base: u32, skin: &SkinTone) -> Vec<u32> {
+    vec![base, tone(skin)]
+}
+
+/// Join the elements with the zero width joiner
+pub fn zwj(elements: &[Vec<u32>]) -> Vec<u32> {
+    elements.join(&ZWJ)
+}
+
+/// Flag of the region, from its ISO 3166-1 alpha-2 code
+pub fn flag(region: &str) -> Vec<u32> {
+    region
+        .chars()
+        .map(|x| REGIONAL_A + (x.to_ascii_uppercase() as u32 - 'A' as u32))
+        .collect()
+}
+
+/// Flag of the subdivision, from its ISO 3166-2 code without the hyphen, e.g. `gbsct`
+pub fn subdivision(code: &str) -> Vec<u32> {
+    std::iter::once(BLACK_FLAG)
+        .chain(
+            code.chars()
+                .map(|x| TAG_BASE + x.to_ascii_lowercase() as u32),
+        )
+        .chain(std::iter::once(CANCEL_TAG))
+        .collect()
+}
+
+/// Keycap of the digit, `#` or `*`
+pub fn keycap(key: char) -> Vec<u32> {
+    vec![key as u32, VS16, KEYCAP]
+}
+
+fn zwj_kind(seq: &[u32]) -> Option<Kind> {
+    if tables::ZWJ.contains(&seq) {
+        return Some(Kind::Zwj);
+    }
+    let tones: Vec<u32> = seq.iter().copied().filter(|&x| is_modifier(x)).collect();
+    if tones.is_empty() {
+        return None;
+    }
+    let template: Vec<u32> = seq
+        .iter()
+        .map(|&x| if is_modifier(x) { tables::TONE } else { x })
+        .collect();
+    let distinct = tones
+        .iter()
+        .enumerate()
+        .all(|(idx, x)| !tones[..idx].contains(x));
+    (tables::ZWJ_TONED.contains(&&template[..])
+        || (distinct && tables::ZWJ_DISTINCT_TONES.contains(&&template[..])))
+    .then_some(Kind::Zwj)
+}
+
+/// Kind of the RGI emoji sequence, return None when the sequence is not in the RGI set
+pub fn kind(seq: &[u32]) -> Option<Kind> {
+    match *seq {
+        [cp] if in_ranges(&tables::PRESENTATION, cp) => Some(Kind::Basic),
+        [cp, VS16] if in_ranges(&tables::TEXT_DEFAULT, cp) => Some(Kind::Basic),
+        [cp, VS16, KEYCAP] if is_keycap_base(cp) => Some(Kind::Keycap),
+        [first, second] if is_regional(first) && is_regional(second) => {
+            let region: String = [first, second]
+                .iter()
+                .filter_map(|&x| char::from_u32(x - REGIONAL_A + 'A' as u32))
+                .collect();
+            tables::FLAGS
+                .contains(&region.as_str())
+                .then_some(Kind::Flag)
+        }
+        [base, cp] if is_modifier_base(base) && is_modifier(cp) => Some(Kind::Modifier),
+        [BLACK_FLAG, ref tags @ .., CANCEL_TAG] => {
+            let code: String = tags
+                .iter()
+                .filter_map(|&x| char::from_u32(x.checked_sub(TAG_BASE)?))
+                .collect();
+            tables::SUBDIVISIONS
+                .contains(&code.as_str())
+                .then_some(Kind::Subdivision)
+        }
+        _ if seq.contains(&ZWJ) => zwj_kind(seq),
+        _ => None,
+    }
+}
+
+/// Name of the kind of the sequence, or `not RGI`
+pub fn status(seq: &[u32]) -> &'static str {
+    kind(seq).map_or("not RGI", |x| x.name())
+}
+
+fn is_emoji_start(cp: u32) -> bool {
+    in_ranges(&tables::PRESENTATION, cp)
+        || in_ranges(&tables::TEXT_DEFAULT, cp)
+        || is_keycap_base(cp)
+        || is_regional(cp)
+}
+
+/// End of the element starting at `pos`, together with its presentation selector, modifier and tags
+fn element_end(buff: &[u32], pos: usize) -> usize {
+    let mut end = pos + 1;
+    while buff
+        .get(end)
+        .is_some_and(|&x| x == VS16 || x == KEYCAP || is_modifier(x) || is_tag(x))
+    {
+        end += 1;
+    }
+    end
+}
+
+/// End of the sequence starting at `start`, following the zero width joiners
+fn sequence_end(buff: &[u32], start: usize) -> usize {
+    if is_regional(buff[start]) {
+        return start
+            + if buff.get(start + 1).is_some_and(|&x| is_regional(x)) {
+                2
+            } else {
+                1
+            };
+    }
+    let mut end = element_end(buff, start);
+    while buff.get(end) == Some(&ZWJ) {
+        end += 1;
+        if buff.get(end).is_some_and(|&x| is_emoji_start(x)) {
+            end = element_end(buff, end);
+        }
+    }
+    end
+}
+
+/** Split the buffer into the emoji sequences, and check each of them against the RGI set
+
+The text between the sequences is skipped, as well as the characters which are only emoji
+when followed by the presentation selector, e.g. `©` or the digits.
+ */
+pub fn sequences(buff: &[u32]) -> Vec<Sequence> {
+    let mut out = Vec::new();
+    let mut pos = 0;
+    while pos < buff.len() {
+        if !is_emoji_start(buff[pos]) {
+            pos += 1;
+            continue;
+        }
+        let end = sequence_end(buff, pos);
+        if end - pos > 1 || in_ranges(&tables::PRESENTATION, buff[pos]) || is_regional(buff[pos]) {
+            out.push(Sequence {
+                start: pos,
+                end,
+                kind: kind(&buff[pos..end]),
+            });
+        }
+        pos = end;
+    }
+    out
+}
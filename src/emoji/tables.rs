@@ -0,0 +1,785 @@
+/*! Data of the RGI emoji sequences, as of Emoji 17.0
+
+The tables are derived from the fully-qualified entries of `emoji-test.txt`.
+The skin tones of the ZWJ sequences are written as [TONE](TONE), standing for any of the five modifiers.
+ */
+
+/// Codepoints which are emoji on their own, including the skin tone and hair components
+pub const PRESENTATION: [(u32, u32); 80] = [
+    (0x231A, 0x231B),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F201, 0x1F201),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F232, 0x1F236),
+    (0x1F238, 0x1F23A),
+    (0x1F250, 0x1F251),
+    (0x1F300, 0x1F320),
+    (0x1F32D, 0x1F335),
+    (0x1F337, 0x1F37C),
+    (0x1F37E, 0x1F393),
+    (0x1F3A0, 0x1F3CA),
+    (0x1F3CF, 0x1F3D3),
+    (0x1F3E0, 0x1F3F0),
+    (0x1F3F4, 0x1F3F4),
+    (0x1F3F8, 0x1F43E),
+    (0x1F440, 0x1F440),
+    (0x1F442, 0x1F4FC),
+    (0x1F4FF, 0x1F53D),
+    (0x1F54B, 0x1F54E),
+    (0x1F550, 0x1F567),
+    (0x1F57A, 0x1F57A),
+    (0x1F595, 0x1F596),
+    (0x1F5A4, 0x1F5A4),
+    (0x1F5FB, 0x1F64F),
+    (0x1F680, 0x1F6C5),
+    (0x1F6CC, 0x1F6CC),
+    (0x1F6D0, 0x1F6D2),
+    (0x1F6D5, 0x1F6D8),
+    (0x1F6DC, 0x1F6DF),
+    (0x1F6EB, 0x1F6EC),
+    (0x1F6F4, 0x1F6FC),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F7F0, 0x1F7F0),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1F9FF),
+    (0x1FA70, 0x1FA7C),
+    (0x1FA80, 0x1FA8A),
+    (0x1FA8E, 0x1FAC6),
+    (0x1FAC8, 0x1FAC8),
+    (0x1FACD, 0x1FADC),
+    (0x1FADF, 0x1FAEA),
+    (0x1FAEF, 0x1FAF8),
+];
+
+/// Codepoints which are emoji when followed by U+FE0F
+pub const TEXT_DEFAULT: [(u32, u32); 116] = [
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21A9, 0x21AA),
+    (0x2328, 0x2328),
+    (0x23CF, 0x23CF),
+    (0x23ED, 0x23EF),
+    (0x23F1, 0x23F2),
+    (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0),
+    (0x25FB, 0x25FC),
+    (0x2600, 0x2604),
+    (0x260E, 0x260E),
+    (0x2611, 0x2611),
+    (0x2618, 0x2618),
+    (0x261D, 0x261D),
+    (0x2620, 0x2620),
+    (0x2622, 0x2623),
+    (0x2626, 0x2626),
+    (0x262A, 0x262A),
+    (0x262E, 0x262F),
+    (0x2638, 0x263A),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x265F, 0x2660),
+    (0x2663, 0x2663),
+    (0x2665, 0x2666),
+    (0x2668, 0x2668),
+    (0x267B, 0x267B),
+    (0x267E, 0x267E),
+    (0x2692, 0x2692),
+    (0x2694, 0x2697),
+    (0x2699, 0x2699),
+    (0x269B, 0x269C),
+    (0x26A0, 0x26A0),
+    (0x26A7, 0x26A7),
+    (0x26B0, 0x26B1),
+    (0x26C8, 0x26C8),
+    (0x26CF, 0x26CF),
+    (0x26D1, 0x26D1),
+    (0x26D3, 0x26D3),
+    (0x26E9, 0x26E9),
+    (0x26F0, 0x26F1),
+    (0x26F4, 0x26F4),
+    (0x26F7, 0x26F9),
+    (0x2702, 0x2702),
+    (0x2708, 0x2709),
+    (0x270C, 0x270D),
+    (0x270F, 0x270F),
+    (0x2712, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271D, 0x271D),
+    (0x2721, 0x2721),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x2763, 0x2764),
+    (0x27A1, 0x27A1),
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F170, 0x1F171),
+    (0x1F17E, 0x1F17F),
+    (0x1F202, 0x1F202),
+    (0x1F237, 0x1F237),
+    (0x1F321, 0x1F321),
+    (0x1F324, 0x1F32C),
+    (0x1F336, 0x1F336),
+    (0x1F37D, 0x1F37D),
+    (0x1F396, 0x1F397),
+    (0x1F399, 0x1F39B),
+    (0x1F39E, 0x1F39F),
+    (0x1F3CB, 0x1F3CE),
+    (0x1F3D4, 0x1F3DF),
+    (0x1F3F3, 0x1F3F3),
+    (0x1F3F5, 0x1F3F5),
+    (0x1F3F7, 0x1F3F7),
+    (0x1F43F, 0x1F43F),
+    (0x1F441, 0x1F441),
+    (0x1F4FD, 0x1F4FD),
+    (0x1F549, 0x1F54A),
+    (0x1F56F, 0x1F570),
+    (0x1F573, 0x1F579),
+    (0x1F587, 0x1F587),
+    (0x1F58A, 0x1F58D),
+    (0x1F590, 0x1F590),
+    (0x1F5A5, 0x1F5A5),
+    (0x1F5A8, 0x1F5A8),
+    (0x1F5B1, 0x1F5B2),
+    (0x1F5BC, 0x1F5BC),
+    (0x1F5C2, 0x1F5C4),
+    (0x1F5D1, 0x1F5D3),
+    (0x1F5DC, 0x1F5DE),
+    (0x1F5E1, 0x1F5E1),
+    (0x1F5E3, 0x1F5E3),
+    (0x1F5E8, 0x1F5E8),
+    (0x1F5EF, 0x1F5EF),
+    (0x1F5F3, 0x1F5F3),
+    (0x1F5FA, 0x1F5FA),
+    (0x1F6CB, 0x1F6CB),
+    (0x1F6CD, 0x1F6CF),
+    (0x1F6E0, 0x1F6E5),
+    (0x1F6E9, 0x1F6E9),
+    (0x1F6F0, 0x1F6F0),
+    (0x1F6F3, 0x1F6F3),
+];
+
+/// Codepoints which take a skin tone modifier
+pub const MODIFIER_BASES: [(u32, u32); 41] = [
+    (0x261D, 0x261D),
+    (0x26F9, 0x26F9),
+    (0x270A, 0x270D),
+    (0x1F385, 0x1F385),
+    (0x1F3C2, 0x1F3C4),
+    (0x1F3C7, 0x1F3C7),
+    (0x1F3CA, 0x1F3CC),
+    (0x1F442, 0x1F443),
+    (0x1F446, 0x1F450),
+    (0x1F466, 0x1F469),
+    (0x1F46B, 0x1F478),
+    (0x1F47C, 0x1F47C),
+    (0x1F481, 0x1F483),
+    (0x1F485, 0x1F487),
+    (0x1F48F, 0x1F48F),
+    (0x1F491, 0x1F491),
+    (0x1F4AA, 0x1F4AA),
+    (0x1F574, 0x1F575),
+    (0x1F57A, 0x1F57A),
+    (0x1F590, 0x1F590),
+    (0x1F595, 0x1F596),
+    (0x1F645, 0x1F647),
+    (0x1F64B, 0x1F64F),
+    (0x1F6A3, 0x1F6A3),
+    (0x1F6B4, 0x1F6B6),
+    (0x1F6C0, 0x1F6C0),
+    (0x1F6CC, 0x1F6CC),
+    (0x1F90C, 0x1F90C),
+    (0x1F90F, 0x1F90F),
+    (0x1F918, 0x1F91F),
+    (0x1F926, 0x1F926),
+    (0x1F930, 0x1F939),
+    (0x1F93C, 0x1F93E),
+    (0x1F977, 0x1F977),
+    (0x1F9B5, 0x1F9B6),
+    (0x1F9B8, 0x1F9B9),
+    (0x1F9BB, 0x1F9BB),
+    (0x1F9CD, 0x1F9CF),
+    (0x1F9D1, 0x1F9DD),
+    (0x1FAC3, 0x1FAC5),
+    (0x1FAF0, 0x1FAF8),
+];
+
+/// Region codes of the flags
+pub const FLAGS: [&str; 259] = [
+    "AC", "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX",
+    "AZ", "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR",
+    "BS", "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM",
+    "CN", "CO", "CP", "CQ", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DG", "DJ", "DK", "DM",
+    "DO", "DZ", "EA", "EC", "EE", "EG", "EH", "ER", "ES", "ET", "EU", "FI", "FJ", "FK", "FM", "FO",
+    "FR", "GA", "GB", "GD", "GE", "GF", "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS",
+    "GT", "GU", "GW", "GY", "HK", "HM", "HN", "HR", "HT", "HU", "IC", "ID", "IE", "IL", "IM", "IN",
+    "IO", "IQ", "IR", "IS", "IT", "JE", "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP",
+    "KR", "KW", "KY", "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA",
+    "MC", "MD", "ME", "MF", "MG", "MH", "MK", "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT",
+    "MU", "MV", "MW", "MX", "MY", "MZ", "NA", "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR",
+    "NU", "NZ", "OM", "PA", "PE", "PF", "PG", "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW",
+    "PY", "QA", "RE", "RO", "RS", "RU", "RW", "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ",
+    "SK", "SL", "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SX", "SY", "SZ", "TA", "TC", "TD", "TF",
+    "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO", "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM",
+    "UN", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI", "VN", "VU", "WF", "WS", "XK", "YE", "YT",
+    "ZA", "ZM", "ZW",
+];
+
+/// Subdivision codes of the tag sequences
+pub const SUBDIVISIONS: [&str; 3] = ["gbeng", "gbsct", "gbwls"];
+
+/// Placeholder for the skin tone in the ZWJ sequences
+pub const TONE: u32 = 0x1F3FB;
+
+/// ZWJ sequences without skin tone
+pub const ZWJ: [&[u32]; 249] = [
+    &[0x1F636, 0x200D, 0x1F32B, 0xFE0F],
+    &[0x1F62E, 0x200D, 0x1F4A8],
+    &[0x1F642, 0x200D, 0x2194, 0xFE0F],
+    &[0x1F642, 0x200D, 0x2195, 0xFE0F],
+    &[0x1F635, 0x200D, 0x1F4AB],
+    &[0x2764, 0xFE0F, 0x200D, 0x1F525],
+    &[0x2764, 0xFE0F, 0x200D, 0x1FA79],
+    &[0x1F441, 0xFE0F, 0x200D, 0x1F5E8, 0xFE0F],
+    &[0x1F9D4, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D4, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F468, 0x200D, 0x1F9B0],
+    &[0x1F468, 0x200D, 0x1F9B1],
+    &[0x1F468, 0x200D, 0x1F9B3],
+    &[0x1F468, 0x200D, 0x1F9B2],
+    &[0x1F469, 0x200D, 0x1F9B0],
+    &[0x1F9D1, 0x200D, 0x1F9B0],
+    &[0x1F469, 0x200D, 0x1F9B1],
+    &[0x1F9D1, 0x200D, 0x1F9B1],
+    &[0x1F469, 0x200D, 0x1F9B3],
+    &[0x1F9D1, 0x200D, 0x1F9B3],
+    &[0x1F469, 0x200D, 0x1F9B2],
+    &[0x1F9D1, 0x200D, 0x1F9B2],
+    &[0x1F471, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F471, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64E, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64E, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F645, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F645, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F646, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F646, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F481, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F481, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64B, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64B, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F647, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F647, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F926, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F926, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F937, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F937, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F468, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F469, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1F393],
+    &[0x1F468, 0x200D, 0x1F393],
+    &[0x1F469, 0x200D, 0x1F393],
+    &[0x1F9D1, 0x200D, 0x1F3EB],
+    &[0x1F468, 0x200D, 0x1F3EB],
+    &[0x1F469, 0x200D, 0x1F3EB],
+    &[0x1F9D1, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F468, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F469, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1F33E],
+    &[0x1F468, 0x200D, 0x1F33E],
+    &[0x1F469, 0x200D, 0x1F33E],
+    &[0x1F9D1, 0x200D, 0x1F373],
+    &[0x1F468, 0x200D, 0x1F373],
+    &[0x1F469, 0x200D, 0x1F373],
+    &[0x1F9D1, 0x200D, 0x1F527],
+    &[0x1F468, 0x200D, 0x1F527],
+    &[0x1F469, 0x200D, 0x1F527],
+    &[0x1F9D1, 0x200D, 0x1F3ED],
+    &[0x1F468, 0x200D, 0x1F3ED],
+    &[0x1F469, 0x200D, 0x1F3ED],
+    &[0x1F9D1, 0x200D, 0x1F4BC],
+    &[0x1F468, 0x200D, 0x1F4BC],
+    &[0x1F469, 0x200D, 0x1F4BC],
+    &[0x1F9D1, 0x200D, 0x1F52C],
+    &[0x1F468, 0x200D, 0x1F52C],
+    &[0x1F469, 0x200D, 0x1F52C],
+    &[0x1F9D1, 0x200D, 0x1F4BB],
+    &[0x1F468, 0x200D, 0x1F4BB],
+    &[0x1F469, 0x200D, 0x1F4BB],
+    &[0x1F9D1, 0x200D, 0x1F3A4],
+    &[0x1F468, 0x200D, 0x1F3A4],
+    &[0x1F469, 0x200D, 0x1F3A4],
+    &[0x1F9D1, 0x200D, 0x1F3A8],
+    &[0x1F468, 0x200D, 0x1F3A8],
+    &[0x1F469, 0x200D, 0x1F3A8],
+    &[0x1F9D1, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F468, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F469, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1F680],
+    &[0x1F468, 0x200D, 0x1F680],
+    &[0x1F469, 0x200D, 0x1F680],
+    &[0x1F9D1, 0x200D, 0x1F692],
+    &[0x1F468, 0x200D, 0x1F692],
+    &[0x1F469, 0x200D, 0x1F692],
+    &[0x1F46E, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46E, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F575, 0xFE0F, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F575, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F482, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F482, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F477, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F477, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F473, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F473, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F935, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F935, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F470, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F470, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F469, 0x200D, 0x1F37C],
+    &[0x1F468, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x200D, 0x1F384],
+    &[0x1F9B8, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B8, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B9, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B9, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D9, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D9, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DA, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DA, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DC, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DC, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DF, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DF, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F486, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F486, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F487, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F487, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B6, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F6B6, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F6B6, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9CD, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CD, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CE, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9CE, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9CE, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1F9AF],
+    &[0x1F9D1, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F468, 0x200D, 0x1F9AF],
+    &[0x1F468, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F469, 0x200D, 0x1F9AF],
+    &[0x1F469, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1F9BC],
+    &[0x1F9D1, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F468, 0x200D, 0x1F9BC],
+    &[0x1F468, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F469, 0x200D, 0x1F9BC],
+    &[0x1F469, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1F9BD],
+    &[0x1F9D1, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F468, 0x200D, 0x1F9BD],
+    &[0x1F468, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F469, 0x200D, 0x1F9BD],
+    &[0x1F469, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F3C3, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C3, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C3, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F3C3, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F3C3, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1FA70],
+    &[0x1F46F, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D6, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D6, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D7, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D7, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CC, 0xFE0F, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CC, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C4, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C4, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6A3, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6A3, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CA, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CA, 0x200D, 0x2640, 0xFE0F],
+    &[0x26F9, 0xFE0F, 0x200D, 0x2642, 0xFE0F],
+    &[0x26F9, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CB, 0xFE0F, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CB, 0xFE0F, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B4, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B4, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B5, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B5, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F938, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F938, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93C, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93C, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93D, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93D, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93E, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93E, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F939, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F939, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D8, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D8, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D1, 0x200D, 0x1F91D, 0x200D, 0x1F9D1],
+    &[
+        0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468,
+    ],
+    &[
+        0x1F468, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468,
+    ],
+    &[
+        0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469,
+    ],
+    &[0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468],
+    &[0x1F468, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468],
+    &[0x1F469, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F467],
+    &[0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F468, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F469, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F466, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F467],
+    &[0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F466],
+    &[0x1F469, 0x200D, 0x1F467, 0x200D, 0x1F467],
+    &[0x1F9D1, 0x200D, 0x1F9D1, 0x200D, 0x1F9D2],
+    &[0x1F9D1, 0x200D, 0x1F9D1, 0x200D, 0x1F9D2, 0x200D, 0x1F9D2],
+    &[0x1F9D1, 0x200D, 0x1F9D2],
+    &[0x1F9D1, 0x200D, 0x1F9D2, 0x200D, 0x1F9D2],
+    &[0x1F415, 0x200D, 0x1F9BA],
+    &[0x1F408, 0x200D, 0x2B1B],
+    &[0x1F43B, 0x200D, 0x2744, 0xFE0F],
+    &[0x1F426, 0x200D, 0x2B1B],
+    &[0x1F426, 0x200D, 0x1F525],
+    &[0x1F34B, 0x200D, 0x1F7E9],
+    &[0x1F344, 0x200D, 0x1F7EB],
+    &[0x26D3, 0xFE0F, 0x200D, 0x1F4A5],
+    &[0x1F3F3, 0xFE0F, 0x200D, 0x1F308],
+    &[0x1F3F3, 0xFE0F, 0x200D, 0x26A7, 0xFE0F],
+    &[0x1F3F4, 0x200D, 0x2620, 0xFE0F],
+];
+
+/// ZWJ sequences taking any skin tone at each [TONE](TONE)
+pub const ZWJ_TONED: [&[u32]; 197] = [
+    &[0x1F9D4, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D4, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B0],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B1],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B3],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9B2],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B0],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B0],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B1],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B1],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B3],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B3],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9B2],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9B2],
+    &[0x1F471, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F471, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64D, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F645, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F645, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F646, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F646, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F481, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F481, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F64B, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F64B, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CF, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CF, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F647, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F647, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F926, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F926, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F937, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F937, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x2695, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F393],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F393],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F393],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3EB],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F3EB],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F3EB],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x2696, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F33E],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F33E],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F33E],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F373],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F373],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F373],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F527],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F527],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F527],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3ED],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F3ED],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F3ED],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F4BC],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F4BC],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F4BC],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F52C],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F52C],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F52C],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F4BB],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F4BB],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F4BB],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3A4],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F3A4],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F3A4],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F3A8],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F3A8],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F3A8],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x2708, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F680],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F680],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F680],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F692],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F692],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F692],
+    &[0x1F46E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F575, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F575, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F482, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F482, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F477, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F477, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F473, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F473, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F935, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F935, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F470, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F470, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F37C],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F37C],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F384],
+    &[0x1F9B8, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B8, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9B9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9B9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DA, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DA, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DB, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DB, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DC, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DC, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9DD, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9DD, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F486, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F486, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F487, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F487, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B6, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B6, 0x1F3FB, 0x200D, 0x27A1, 0xFE0F],
+    &[
+        0x1F6B6, 0x1F3FB, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F,
+    ],
+    &[
+        0x1F6B6, 0x1F3FB, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F,
+    ],
+    &[0x1F9CD, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CD, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9CE, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9CE, 0x1F3FB, 0x200D, 0x27A1, 0xFE0F],
+    &[
+        0x1F9CE, 0x1F3FB, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F,
+    ],
+    &[
+        0x1F9CE, 0x1F3FB, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F,
+    ],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9AF],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9AF],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9AF],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9AF, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BC],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BC],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BC],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BC, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BD],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BD],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BD],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F9BD, 0x200D, 0x27A1, 0xFE0F],
+    &[0x1F3C3, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C3, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C3, 0x1F3FB, 0x200D, 0x27A1, 0xFE0F],
+    &[
+        0x1F3C3, 0x1F3FB, 0x200D, 0x2640, 0xFE0F, 0x200D, 0x27A1, 0xFE0F,
+    ],
+    &[
+        0x1F3C3, 0x1F3FB, 0x200D, 0x2642, 0xFE0F, 0x200D, 0x27A1, 0xFE0F,
+    ],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1FA70],
+    &[0x1F46F, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F46F, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D6, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D6, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D7, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D7, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CC, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CC, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3C4, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3C4, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6A3, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6A3, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CA, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CA, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x26F9, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x26F9, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F3CB, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F3CB, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B4, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B4, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F6B5, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F6B5, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F938, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F938, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93C, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93C, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93D, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93D, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F93E, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F93E, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F939, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F939, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D8, 0x1F3FB, 0x200D, 0x2642, 0xFE0F],
+    &[0x1F9D8, 0x1F3FB, 0x200D, 0x2640, 0xFE0F],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F9D1, 0x1F3FB],
+    &[
+        0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB,
+    ],
+    &[
+        0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F468, 0x1F3FB,
+    ],
+    &[
+        0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F469, 0x1F3FB,
+    ],
+    &[
+        0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB,
+    ],
+    &[
+        0x1F468, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F468, 0x1F3FB,
+    ],
+    &[
+        0x1F469, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F469, 0x1F3FB,
+    ],
+];
+
+/// ZWJ sequences taking two different skin tones
+pub const ZWJ_DISTINCT_TONES: [&[u32]; 12] = [
+    &[0x1FAF1, 0x1F3FB, 0x200D, 0x1FAF2, 0x1F3FB],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F9D1, 0x1F3FB],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F430, 0x200D, 0x1F469, 0x1F3FB],
+    &[0x1F9D1, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F9D1, 0x1F3FB],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1FAEF, 0x200D, 0x1F469, 0x1F3FB],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F469, 0x1F3FB],
+    &[0x1F469, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[0x1F468, 0x1F3FB, 0x200D, 0x1F91D, 0x200D, 0x1F468, 0x1F3FB],
+    &[
+        0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F48B, 0x200D, 0x1F9D1, 0x1F3FB,
+    ],
+    &[
+        0x1F9D1, 0x1F3FB, 0x200D, 0x2764, 0xFE0F, 0x200D, 0x1F9D1, 0x1F3FB,
+    ],
+];
@@ -0,0 +1,89 @@
+/*! Testing submodule for the emoji module
+ */
+
+use super::*;
+use crate::testing::codepoints;
+
+#[test]
+fn build() {
+    assert_eq!(modifier(0x1F44D, &SkinTone::Medium), codepoints("👍🏽"));
+    assert_eq!(
+        zwj(&[codepoints("👩"), codepoints("💻")]),
+        vec![0x1F469, ZWJ, 0x1F4BB]
+    );
+    assert_eq!(flag("jp"), codepoints("🇯🇵"));
+    assert_eq!(
+        subdivision("gbsct"),
+        vec![0x1F3F4, 0xE0067, 0xE0062, 0xE0073, 0xE0063, 0xE0074, 0xE007F]
+    );
+    assert_eq!(keycap('#'), vec![0x23, VS16, 0x20E3]);
+}
+
+#[test]
+fn rgi() {
+    assert_eq!(kind(&codepoints("😀")), Some(Kind::Basic));
+    assert_eq!(kind(&[0x2764, VS16]), Some(Kind::Basic));
+    assert_eq!(kind(&[0x2764]), None);
+    assert_eq!(kind(&keycap('7')), Some(Kind::Keycap));
+    assert_eq!(kind(&keycap('a')), None);
+    assert_eq!(kind(&flag("FR")), Some(Kind::Flag));
+    assert_eq!(kind(&flag("XY")), None);
+    assert_eq!(kind(&subdivision("gbwls")), Some(Kind::Subdivision));
+    assert_eq!(kind(&subdivision("usca")), None);
+    assert_eq!(kind(&codepoints("👋🏿")), Some(Kind::Modifier));
+    assert_eq!(kind(&codepoints("🐶🏿")), None);
+}
+
+#[test]
+fn rgi_zwj() {
+    assert_eq!(kind(&codepoints("👩‍💻")), Some(Kind::Zwj));
+    assert_eq!(kind(&codepoints("👩🏾‍💻")), Some(Kind::Zwj));
+    assert_eq!(kind(&codepoints("🏳️‍🌈")), Some(Kind::Zwj));
+    // The presentation selector is required in the fully-qualified sequence
+    assert_eq!(kind(&codepoints("🏳‍🌈")), None);
+    assert_eq!(kind(&codepoints("💻‍👩")), None);
+    // The two persons holding hands may share the tone, the kissing persons may not
+    assert_eq!(kind(&codepoints("🧑🏻‍🤝‍🧑🏻")), Some(Kind::Zwj));
+    assert_eq!(kind(&codepoints("🧑🏻‍❤️‍💋‍🧑🏼")), Some(Kind::Zwj));
+    assert_eq!(kind(&codepoints("🧑🏻‍❤️‍💋‍🧑🏻")), None);
+}
+
+#[test]
+fn split() {
+    let buff = codepoints("Hi 👩‍💻👍🏽 🇯🇵🇫 © 1️⃣ 🐶🏿 a‍b");
+    assert_eq!(
+        sequences(&buff),
+        vec![
+            Sequence {
+                start: 3,
+                end: 6,
+                kind: Some(Kind::Zwj)
+            },
+            Sequence {
+                start: 6,
+                end: 8,
+                kind: Some(Kind::Modifier)
+            },
+            Sequence {
+                start: 9,
+                end: 11,
+                kind: Some(Kind::Flag)
+            },
+            Sequence {
+                start: 11,
+                end: 12,
+                kind: None
+            },
+            Sequence {
+                start: 15,
+                end: 18,
+                kind: Some(Kind::Keycap)
+            },
+            Sequence {
+                start: 19,
+                end: 21,
+                kind: None
+            },
+        ]
+    );
+}
@@ -0,0 +1,189 @@
+/*! Composition and decomposition of the Hangul syllables
+
+Every precomposed syllable is obtained from the index of its jamo:
+`U+AC00 + (lead × 21 + vowel) × 28 + tail`, where the tail 0 stands for no final consonant.
+The romanized input follows the Revised Romanization, with `-` to separate the syllables
+when the split is ambiguous, e.g. `han-geul` rather than `hang-eul`.
+ */
+
+#[cfg(test)]
+mod testing;
+
+use std::collections::HashSet;
+
+/// First precomposed syllable
+pub const SYLLABLE_BASE: u32 = 0xAC00;
+pub const LEAD_COUNT: usize = 19;
+pub const VOWEL_COUNT: usize = 21;
+pub const TAIL_COUNT: usize = 28;
+/// First conjoining jamo of each kind, the tail 0 being before the first final consonant
+const LEAD_BASE: u32 = 0x1100;
+const VOWEL_BASE: u32 = 0x1161;
+const TAIL_BASE: u32 = 0x11A7;
+
+/// Romanization of the initial consonants
+pub const LEADS: [&str; LEAD_COUNT] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p",
+    "h",
+];
+/// Romanization of the vowels
+pub const VOWELS: [&str; VOWEL_COUNT] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+/// Romanization of the final consonants accepted in the input, by index of the tail
+const TAILS: [&[&str]; TAIL_COUNT] = [
+    &[""],
+    &["g", "k"],
+    &["kk"],
+    &["gs", "ks"],
+    &["n"],
+    &["nj"],
+    &["nh"],
+    &["d", "t"],
+    &["l", "r"],
+    &["lg", "lk"],
+    &["lm"],
+    &["lb"],
+    &["ls"],
+    &["lt"],
+    &["lp"],
+    &["lh"],
+    &["m"],
+    &["b", "p"],
+    &["bs", "ps"],
+    &["s"],
+    &["ss"],
+    &["ng"],
+    &["j"],
+    &["ch"],
+    &[],
+    &[],
+    &[],
+    &["h"],
+];
+
+/// Jamo of a syllable, by index
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Jamo {
+    pub lead: usize,
+    pub vowel: usize,
+    pub tail: usize,
+}
+
+/// Compose the syllable, return None when any index is out of range
+pub fn compose(jamo: Jamo) -> Option<u32> {
+    if jamo.lead >= LEAD_COUNT || jamo.vowel >= VOWEL_COUNT || jamo.tail >= TAIL_COUNT {
+        return None;
+    }
+    Some(SYLLABLE_BASE + ((jamo.lead * VOWEL_COUNT + jamo.vowel) * TAIL_COUNT + jamo.tail) as u32)
+}
+
+/// Decompose the syllable, return None when the codepoint is not a precomposed syllable
+pub fn decompose(code_point: u32) -> Option<Jamo> {
+    let idx = code_point.checked_sub(SYLLABLE_BASE)? as usize;
+    if idx >= LEAD_COUNT * VOWEL_COUNT * TAIL_COUNT {
+        return None;
+    }
+    Some(Jamo {
+        lead: idx / (VOWEL_COUNT * TAIL_COUNT),
+        vowel: idx % (VOWEL_COUNT * TAIL_COUNT) / TAIL_COUNT,
+        tail: idx % TAIL_COUNT,
+    })
+}
+
+/// Conjoining jamo of the syllable, without the tail when there is no final consonant
+pub fn conjoining(jamo: Jamo) -> Vec<u32> {
+    let mut out = vec![LEAD_BASE + jamo.lead as u32, VOWEL_BASE + jamo.vowel as u32];
+    if jamo.tail != 0 {
+        out.push(TAIL_BASE + jamo.tail as u32);
+    }
+    out
+}
+
+/// Show the arithmetic leading from the jamo to the syllable, return None when any index is out of range
+pub fn arithmetic(jamo: Jamo) -> Option<String> {
+    let syllable = compose(jamo)?;
+    Some(format!(
+        "U+{:04X} + ({} × {} + {}) × {} + {} = U+{:04X}",
+        SYLLABLE_BASE, jamo.lead, VOWEL_COUNT, jamo.vowel, TAIL_COUNT, jamo.tail, syllable
+    ))
+}
+
+/// Index and length of the romanizations matching the start of the input, longest first
+fn matching(inp: &str, table: &[&str]) -> Vec<(usize, usize)> {
+    let mut found: Vec<(usize, usize)> = table
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| inp.starts_with(*x))
+        .map(|(idx, x)| (idx, x.len()))
+        .collect();
+    found.sort_by_key(|x| std::cmp::Reverse(x.1));
+    found
+}
+
+/** Read the syllables of the word from the byte offset `pos`, preferring the shortest final consonant
+
+The offsets from which the rest of the word could not be read are kept in `failed`,
+so each of them is only tried once.
+ */
+fn parse_syllables(word: &str, pos: usize, failed: &mut HashSet<usize>) -> Option<Vec<Jamo>> {
+    let inp = &word[pos..];
+    if inp.is_empty() {
+        return Some(Vec::new());
+    }
+    if failed.contains(&pos) {
+        return None;
+    }
+    let mut leads = matching(inp, &LEADS);
+    // The `l` is read as `r` at the start of the syllable
+    if inp.starts_with('l') {
+        leads.push((5, 1));
+    }
+    for (lead, lead_len) in leads {
+        let rest = &inp[lead_len..];
+        for (vowel, vowel_len) in matching(rest, &VOWELS) {
+            let rest = &rest[vowel_len..];
+            let mut tails: Vec<(usize, usize)> = TAILS
+                .iter()
+                .enumerate()
+                .flat_map(|(idx, names)| names.iter().map(move |x| (idx, *x)))
+                .filter(|(_, x)| rest.starts_with(*x))
+                .map(|(idx, x)| (idx, x.len()))
+                .collect();
+            tails.sort_by_key(|x| x.1);
+            for (tail, tail_len) in tails {
+                let next_pos = pos + lead_len + vowel_len + tail_len;
+                if let Some(mut next) = parse_syllables(word, next_pos, failed) {
+                    next.insert(0, Jamo { lead, vowel, tail });
+                    return Some(next);
+                }
+            }
+        }
+    }
+    failed.insert(pos);
+    None
+}
+
+/** Compose the syllables from the romanized text
+
+The words are separated by a space, which is kept in the output.
+Return None when a word could not be read as a sequence of syllables.
+ */
+pub fn parse_roman(inp: &str) -> Option<Vec<u32>> {
+    let mut out = Vec::new();
+    for (idx, word) in inp.split(' ').enumerate() {
+        if idx > 0 {
+            out.push(' ' as u32);
+        }
+        for part in word.to_ascii_lowercase().split(['-', '.']) {
+            if part.is_empty() {
+                return None;
+            }
+            for jamo in parse_syllables(part, 0, &mut HashSet::new())? {
+                out.push(compose(jamo)?);
+            }
+        }
+    }
+    Some(out)
+}
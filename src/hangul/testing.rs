@@ -0,0 +1,72 @@
+/*! Testing submodule for the hangul module
+ */
+
+use super::*;
+use crate::testing::codepoints;
+
+#[test]
+fn compose_syllable() {
+    let han = Jamo {
+        lead: 18,
+        vowel: 0,
+        tail: 4,
+    };
+    assert_eq!(compose(han), Some('한' as u32));
+    assert_eq!(decompose('한' as u32), Some(han));
+    assert_eq!(
+        decompose(0xAC00),
+        Some(Jamo {
+            lead: 0,
+            vowel: 0,
+            tail: 0
+        })
+    );
+    assert_eq!(
+        compose(Jamo {
+            lead: 19,
+            vowel: 0,
+            tail: 0
+        }),
+        None
+    );
+    assert_eq!(decompose(0xD7A4), None);
+    assert_eq!(decompose('a' as u32), None);
+}
+
+#[test]
+fn arithmetic_steps() {
+    let han = Jamo {
+        lead: 18,
+        vowel: 0,
+        tail: 4,
+    };
+    assert_eq!(
+        arithmetic(han),
+        Some("U+AC00 + (18 × 21 + 0) × 28 + 4 = U+D55C".to_string())
+    );
+    assert_eq!(conjoining(han), vec![0x1112, 0x1161, 0x11AB]);
+    assert_eq!(
+        conjoining(decompose('가' as u32).unwrap()),
+        vec![0x1100, 0x1161]
+    );
+}
+
+#[test]
+fn roman() {
+    assert_eq!(parse_roman("han-geul"), Some(codepoints("한글")));
+    assert_eq!(parse_roman("hangeul"), Some(codepoints("한글")));
+    assert_eq!(parse_roman("Annyeong"), Some(codepoints("안녕")));
+    assert_eq!(parse_roman("seoul"), Some(codepoints("서울")));
+    assert_eq!(parse_roman("dak gwa"), Some(codepoints("닥 과")));
+    assert_eq!(parse_roman("hang-eul"), Some(codepoints("항을")));
+    assert_eq!(parse_roman("xyz"), None);
+    assert_eq!(parse_roman("han--geul"), None);
+}
+
+#[test]
+fn roman_unreadable_long() {
+    // Each syllable may end with a consonant starting the next one, so the failure is only
+    // reached in time when each offset is tried once
+    assert_eq!(parse_roman(&("ga".repeat(200) + "x")), None);
+    assert_eq!(parse_roman(&"ga".repeat(200)).map(|x| x.len()), Some(200));
+}
@@ -0,0 +1,154 @@
+/*! Conversion of the internationalized domain names
+
+The UTS #46 mapping, the Punycode conversion, and the bidi rule are done by the `idna` crate.
+The contextual rules of RFC 5892 (CONTEXTJ and CONTEXTO) are not checked by the crate,
+so they are checked here on the labels of the buffer, with the joining types
+of ArabicShaping.txt provided by the `unicode-joining-type` crate.
+ */
+
+#[cfg(test)]
+mod testing;
+
+use unicode_joining_type::{get_joining_type, JoiningType};
+use unicode_normalization::char::canonical_combining_class;
+use unicode_script::{Script, UnicodeScript};
+
+/// Failure of the conversion
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The buffer contains codepoints which are not valid
+    InvalidCodepoint,
+    /// The UTS #46 processing failed, with the list of the failed checks
+    Uts46(String),
+    /// The contextual rules are violated at the positions of the codepoints, within the mapped domain
+    Context(Vec<usize>),
+}
+
+/// Canonical combining class of the viramas
+const VIRAMA: u8 = 9;
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// Separators of the labels, mapped to U+002E by UTS #46
+fn is_separator(c: char) -> bool {
+    matches!(c, '.' | '\u{3002}' | '\u{FF0E}' | '\u{FF61}')
+}
+
+/// Check the CONTEXTJ rules of RFC 5892 Appendix A.1 and A.2 at `pos`
+fn joiner_allowed(label: &[char], pos: usize) -> bool {
+    if pos > 0 && canonical_combining_class(label[pos - 1]) == VIRAMA {
+        return true;
+    }
+    if label[pos] == ZWJ {
+        return false;
+    }
+    let before = label[..pos]
+        .iter()
+        .rev()
+        .map(|&c| get_joining_type(c))
+        .find(|&x| x != JoiningType::Transparent);
+    let after = label[pos + 1..]
+        .iter()
+        .map(|&c| get_joining_type(c))
+        .find(|&x| x != JoiningType::Transparent);
+    matches!(
+        before,
+        Some(JoiningType::LeftJoining | JoiningType::DualJoining)
+    ) && matches!(
+        after,
+        Some(JoiningType::RightJoining | JoiningType::DualJoining)
+    )
+}
+
+/// Check the CONTEXTO rules of RFC 5892 Appendix A.3 to A.9 at `pos`
+fn other_allowed(label: &[char], pos: usize) -> bool {
+    match label[pos] {
+        '\u{00B7}' => pos > 0 && label[pos - 1] == 'l' && label.get(pos + 1) == Some(&'l'),
+        '\u{0375}' => label
+            .get(pos + 1)
+            .is_some_and(|c| c.script() == Script::Greek),
+        '\u{05F3}' | '\u{05F4}' => pos > 0 && label[pos - 1].script() == Script::Hebrew,
+        '\u{30FB}' => label.iter().any(|c| {
+            matches!(
+                c.script(),
+                Script::Hiragana | Script::Katakana | Script::Han
+            )
+        }),
+        '\u{0660}'..='\u{0669}' => !label.iter().any(|c| matches!(c, '\u{06F0}'..='\u{06F9}')),
+        '\u{06F0}'..='\u{06F9}' => !label.iter().any(|c| matches!(c, '\u{0660}'..='\u{0669}')),
+        _ => true,
+    }
+}
+
+fn is_contextual(c: char) -> bool {
+    matches!(
+        c,
+        ZWNJ | ZWJ
+            | '\u{00B7}'
+            | '\u{0375}'
+            | '\u{05F3}'
+            | '\u{05F4}'
+            | '\u{30FB}'
+            | '\u{0660}'..='\u{0669}'
+            | '\u{06F0}'..='\u{06F9}'
+    )
+}
+
+/// List the positions of the codepoints violating the contextual rules
+pub fn context_violations(text: &[char]) -> Vec<usize> {
+    let mut violations = Vec::new();
+    let mut start = 0;
+    for label in text.split(|&c| is_separator(c)) {
+        for pos in 0..label.len() {
+            let allowed = match label[pos] {
+                ZWNJ | ZWJ => joiner_allowed(label, pos),
+                c if is_contextual(c) => other_allowed(label, pos),
+                _ => true,
+            };
+            if !allowed {
+                violations.push(start + pos);
+            }
+        }
+        start += label.len() + 1;
+    }
+    violations
+}
+
+fn config() -> idna::Config {
+    idna::Config::default()
+        .use_std3_ascii_rules(true)
+        .check_hyphens(true)
+        .verify_dns_length(true)
+}
+
+/// Convert the buffer to an ASCII domain, with the non-ASCII labels in Punycode
+pub fn to_ascii(buff: &[u32]) -> Result<String, Error> {
+    let text: Vec<char> = buff
+        .iter()
+        .map(|&x| char::from_u32(x))
+        .collect::<Option<_>>()
+        .ok_or(Error::InvalidCodepoint)?;
+    let text: String = text.iter().collect();
+    // The contextual rules apply to the labels once mapped, e.g. `L·L` is mapped to `l·l`
+    let (mapped, result) = config().to_unicode(&text);
+    result.map_err(|err| Error::Uts46(format!("{:?}", err)))?;
+    let violations = context_violations(&mapped.chars().collect::<Vec<char>>());
+    if !violations.is_empty() {
+        return Err(Error::Context(violations));
+    }
+    config()
+        .to_ascii(&text)
+        .map_err(|err| Error::Uts46(format!("{:?}", err)))
+}
+
+/// Convert an ASCII domain, with the labels prefixed by `xn--`, back to the codepoints
+pub fn to_unicode(domain: &str) -> Result<Vec<u32>, Error> {
+    let (text, result) = config().to_unicode(domain);
+    result.map_err(|err| Error::Uts46(format!("{:?}", err)))?;
+    let text: Vec<char> = text.chars().collect();
+    let violations = context_violations(&text);
+    if !violations.is_empty() {
+        return Err(Error::Context(violations));
+    }
+    Ok(text.into_iter().map(|x| x as u32).collect())
+}
@@ -0,0 +1,91 @@
+/*! Testing submodule for the idn module
+ */
+
+use super::*;
+use crate::testing::codepoints;
+
+#[test]
+fn ascii_domain() {
+    assert_eq!(
+        to_ascii(&codepoints("Bücher.example")),
+        Ok("xn--bcher-kva.example".to_string())
+    );
+    assert_eq!(
+        to_ascii(&codepoints("例え。テスト")),
+        Ok("xn--r8jz45g.xn--zckzah".to_string())
+    );
+    assert_eq!(to_ascii(&[0xD800]), Err(Error::InvalidCodepoint));
+    assert!(matches!(
+        to_ascii(&codepoints("-abc.example")),
+        Err(Error::Uts46(_))
+    ));
+}
+
+#[test]
+fn unicode_label() {
+    assert_eq!(to_unicode("xn--bcher-kva"), Ok(codepoints("bücher")));
+    assert_eq!(
+        to_unicode("xn--r8jz45g.xn--zckzah"),
+        Ok(codepoints("例え.テスト"))
+    );
+    assert!(matches!(to_unicode("xn--a"), Err(Error::Uts46(_))));
+}
+
+#[test]
+fn bidi_rule() {
+    // Right-to-left label starting with a digit
+    assert!(matches!(
+        to_ascii(&codepoints("1\u{05D0}.example")),
+        Err(Error::Uts46(_))
+    ));
+    assert!(to_ascii(&codepoints("\u{05D0}\u{05D1}.example")).is_ok());
+}
+
+#[test]
+fn contextj() {
+    // ZWNJ after a virama
+    assert!(context_violations(&['\u{0915}', '\u{094D}', ZWNJ, '\u{0937}']).is_empty());
+    // ZWNJ between a dual and a right joining letter, across a transparent mark
+    assert!(context_violations(&['\u{0628}', '\u{064E}', ZWNJ, '\u{0627}']).is_empty());
+    assert_eq!(context_violations(&['a', ZWNJ, 'b']), vec![1]);
+    assert_eq!(context_violations(&['\u{0628}', ZWJ, '\u{0627}']), vec![1]);
+    // Adlam and Hanifi Rohingya letters, with a transparent Adlam mark
+    assert!(context_violations(&['\u{1E900}', '\u{1E944}', ZWNJ, '\u{1E901}']).is_empty());
+    assert!(context_violations(&['\u{10D01}', ZWNJ, '\u{10D22}']).is_empty());
+    assert_eq!(
+        context_violations(&['\u{10D22}', ZWNJ, '\u{10D01}']),
+        vec![1]
+    );
+    // The soft hyphen is transparent, while the Arabic number sign does not join
+    assert!(context_violations(&['\u{0628}', '\u{00AD}', ZWNJ, '\u{0627}']).is_empty());
+    assert_eq!(context_violations(&['\u{0600}', ZWNJ, '\u{0627}']), vec![1]);
+    assert_eq!(
+        to_ascii(&codepoints("ab.c\u{200D}d")),
+        Err(Error::Context(vec![4]))
+    );
+    // The rules are checked once the labels are mapped to lowercase
+    assert_eq!(
+        to_ascii(&codepoints("paL\u{00B7}La.cat")),
+        to_ascii(&codepoints("pal\u{00B7}la.cat"))
+    );
+    assert!(to_ascii(&codepoints("\u{FF4C}\u{00B7}\u{FF4C}")).is_ok());
+    assert_eq!(
+        to_ascii(&codepoints("Pa\u{00B7}La.cat")),
+        Err(Error::Context(vec![2]))
+    );
+}
+
+#[test]
+fn contexto() {
+    let check = |text: &str| context_violations(&text.chars().collect::<Vec<_>>());
+    assert!(check("l\u{00B7}l").is_empty());
+    assert_eq!(check("a\u{00B7}l"), vec![1]);
+    assert!(check("\u{0375}\u{03B1}").is_empty());
+    assert_eq!(check("\u{0375}a"), vec![0]);
+    assert!(check("\u{05D0}\u{05F3}").is_empty());
+    assert_eq!(check("\u{05F4}"), vec![0]);
+    assert!(check("\u{30AB}\u{30FB}").is_empty());
+    assert_eq!(check("a\u{30FB}"), vec![1]);
+    assert_eq!(check("\u{0660}\u{06F0}"), vec![0, 1]);
+    assert!(check("\u{0660}.\u{06F0}").is_empty());
+}
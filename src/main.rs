@@ -1,4 +1,22 @@
+mod buffers;
 mod cmd;
+mod codec;
+mod codepoint;
+mod compare;
+mod confusable;
+mod diff;
+mod emoji;
+mod hangul;
+mod idn;
+mod mojibake;
+mod reveal;
+#[cfg(test)]
+mod testing;
+mod transfer;
+mod transform;
+mod variation;
+use base64::Engine;
+use std::collections::HashMap;
 use std::io::{self, Write};
 use unicode_normalization::UnicodeNormalization;
 
@@ -11,6 +29,8 @@ fn stdreader() -> io::Result<String> {
 }
 fn main() {
     let mut vecbuff: Vec<u32> = Vec::new();
+    let mut buffers = buffers::BufferSet::new();
+    let mut registers: HashMap<char, Vec<u32>> = HashMap::new();
     while let Ok(inp) = stdreader() {
         let input = match cmd::capture(&inp) {
             Some(x) => x,
@@ -28,8 +48,78 @@ fn main() {
                 match base {
                     cmd::RawBase::Dec => println!("{:?}", vecbuff),
                     cmd::RawBase::Hex => println!("{:02X?}", vecbuff),
+                    cmd::RawBase::Oct => println!(
+                        "[{}]",
+                        vecbuff
+                            .iter()
+                            .map(|x| format!("{:o}", x))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                    cmd::RawBase::Bin => println!(
+                        "[{}]",
+                        vecbuff
+                            .iter()
+                            .map(|x| format!("{:b}", x))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
                 };
             }
+            cmd::Commands::PrintNotation => println!(
+                "{}",
+                vecbuff
+                    .iter()
+                    .map(|&x| codepoint::notation(x))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
+            cmd::Commands::PrintTable { page } => {
+                let pages = vecbuff.len().div_ceil(cmd::TABLE_PAGE).max(1);
+                if page > pages {
+                    println!(
+                        "Unable to print page {}, as buffer only contains {} {}",
+                        page,
+                        pages,
+                        if pages > 1 { "pages" } else { "page" }
+                    );
+                    continue;
+                }
+                let start = (page - 1) * cmd::TABLE_PAGE;
+                let end = (start + cmd::TABLE_PAGE).min(vecbuff.len());
+                for (idx, &cp) in vecbuff.iter().enumerate().take(end).skip(start) {
+                    println!(
+                        "{:>5} {:<3} {:<8} {:<11} {}",
+                        idx,
+                        codepoint::glyph(cp),
+                        codepoint::notation(cp),
+                        codepoint::utf8_bytes(cp),
+                        codepoint::name(cp)
+                    );
+                }
+                println!("Page {}/{}", page, pages);
+            }
+            cmd::Commands::PrintEncoded => {
+                println!(
+                    "{:>5} {:<8} {:<11} {:<39} {:<11} UTF-32BE",
+                    "", "", "UTF-8", "", "UTF-16BE"
+                );
+                for (idx, &cp) in vecbuff.iter().enumerate() {
+                    println!(
+                        "{:>5} {:<8} {:<11} {:<39} {:<11} {}",
+                        idx,
+                        codepoint::notation(cp),
+                        codepoint::utf8_bytes(cp),
+                        codepoint::utf8_bits(cp),
+                        codepoint::utf16_bytes(cp),
+                        codec::bitsplitter(&[cp], true)
+                            .iter()
+                            .map(|x| format!("{:02X}", x))
+                            .collect::<Vec<String>>()
+                            .join(" ")
+                    );
+                }
+            }
             cmd::Commands::InsertLit { pos: ps, chr } => {
                 let pos = ps;
                 if pos > vecbuff.len() {
@@ -39,53 +129,67 @@ fn main() {
                     vecbuff.insert(pos, chr);
                 }
             }
-            cmd::command_list::Commands::Write { enc, file } => {
-                let Some(utf_rep) = render_buffer(&vecbuff) else {
-                    println!("The buffer is not in a valid state");
-                    continue;
-                };
-                match enc {
-                    cmd::command_list::EncodingType::UTF8 => {
-                        match std::fs::write(file.as_str(), utf_rep.iter().collect::<String>()) {
-                            Ok(_) => (),
-                            Err(_) => println!("Unable to write to file {}", file),
-                        };
-                    }
-                    cmd::command_list::EncodingType::UTF32 => {
-                        let mut splitted: Vec<u8> = bitsplitter(&vecbuff, false);
-                        let mut comb: Vec<u8> = vec![0x00 as u8, 0x00, 0xFE, 0xFF];
-                        comb.append(&mut splitted);
-                        match std::fs::write(file.as_str(), comb) {
-                            Ok(_) => (),
-                            Err(_) => println!("Unable to write to file {}", file),
-                        };
+            cmd::command_list::Commands::Write {
+                enc,
+                file,
+                fallback,
+                bom,
+            } => match codec::encode_file(&vecbuff, &enc, fallback, bom) {
+                Ok((bytes, pos)) => {
+                    if !pos.is_empty() {
+                        println!(
+                            "Substituted the elements unrepresentable in {} : {}",
+                            encoding_name(&enc),
+                            list_positions(&vecbuff, &pos)
+                        );
                     }
-                    cmd::command_list::EncodingType::UTF32LE => {
-                        let mut splitted: Vec<u8> = bitsplitter(&vecbuff, true);
-                        let mut comb: Vec<u8> = vec![0xFF as u8, 0xFE, 0x00, 0x00];
-                        comb.append(&mut splitted);
-                        match std::fs::write(file.as_str(), comb) {
-                            Ok(_) => (),
-                            Err(_) => println!("Unable to write to file {}", file),
-                        };
-                    }
-                };
-            }
-            cmd::command_list::Commands::Read { file } => {
-                vecbuff = match std::fs::read_to_string(file.as_str()) {
+                    match std::fs::write(file.as_str(), bytes) {
+                        Ok(_) => (),
+                        Err(_) => println!("Unable to write to file {}", file),
+                    };
+                }
+                Err(codec::EncodeError::NoBom) => {
+                    println!("There is no byte order mark in {}", encoding_name(&enc))
+                }
+                Err(codec::EncodeError::Unrepresentable(pos)) => println!(
+                    "Unable to represent the elements in {} : {}",
+                    encoding_name(&enc),
+                    list_positions(&vecbuff, &pos)
+                ),
+            },
+            cmd::command_list::Commands::Read { enc, file } => {
+                let bytes = match std::fs::read(file.as_str()) {
                     Ok(x) => x,
                     Err(_) => {
                         println!("Unable to open file {}", file);
                         continue;
                     }
-                }
-                .chars()
-                .map(|x| x as u32)
-                .collect();
+                };
+                let decoded = codec::decode_file(&bytes, &enc);
+                report_decode_errors(&decoded.errors);
+                vecbuff = decoded.codepoints;
             }
             cmd::command_list::Commands::Help => {
                 println!("Help Page Here")
             }
+            cmd::command_list::Commands::Size => {
+                for enc in [
+                    cmd::command_list::EncodingType::UTF8,
+                    cmd::command_list::EncodingType::UTF16,
+                    cmd::command_list::EncodingType::UTF32,
+                    cmd::command_list::EncodingType::UTF7,
+                    cmd::command_list::EncodingType::SCSU,
+                    cmd::command_list::EncodingType::BOCU1,
+                ] {
+                    match codec::encode(&vecbuff, &enc) {
+                        Ok(x) => println!("{:<8} : {} bytes", encoding_name(&enc), x.len()),
+                        Err(_) => {
+                            println!("Unable to encode the buffer, as it is not in a valid state");
+                            break;
+                        }
+                    }
+                }
+            }
             cmd::command_list::Commands::Compress => {
                 vecbuff = vecbuff
                     .iter()
@@ -95,12 +199,7 @@ fn main() {
                     .collect();
             }
             cmd::command_list::Commands::Decompress => {
-                vecbuff = vecbuff
-                    .iter()
-                    .map(|&code_point| char::from_u32(code_point).unwrap())
-                    .nfd()
-                    .map(|code_point| code_point as u32)
-                    .collect();
+                vecbuff = transform::decompose(&vecbuff);
             }
             cmd::command_list::Commands::InsertStr { pos, txt } => {
                 let pos = if pos > vecbuff.len() {
@@ -147,10 +246,42 @@ fn main() {
                     vecbuff.remove(pos);
                 }
             }
+            cmd::command_list::Commands::Render(
+                enc @ (cmd::command_list::EncodingType::UTF7
+                | cmd::command_list::EncodingType::IMAPUTF7),
+            ) => match codec::encode(&vecbuff, &enc) {
+                Ok(x) => println!("{}", String::from_utf8_lossy(&x)),
+                Err(_) => println!("Unable to render the buffer"),
+            },
             cmd::command_list::Commands::Render(_) => match render_buffer(&vecbuff) {
                 Some(x) => println!("{:?}", x),
                 None => println!("Unable to render the buffer"),
             },
+            cmd::command_list::Commands::RenderReveal => println!("{}", reveal::reveal(&vecbuff)),
+            cmd::command_list::Commands::RenderPercent { query } => {
+                match codec::encode(&vecbuff, &cmd::command_list::EncodingType::UTF8) {
+                    Ok(x) => println!("{}", transfer::percent_encode(&x, query)),
+                    Err(_) => println!("Unable to render the buffer"),
+                }
+            }
+            cmd::command_list::Commands::RenderBase64(enc) => match codec::encode(&vecbuff, &enc) {
+                Ok(x) => println!("{}", base64::engine::general_purpose::STANDARD.encode(x)),
+                Err(pos) => println!(
+                    "Unable to represent the elements in {} : {}",
+                    encoding_name(&enc),
+                    list_positions(&vecbuff, &pos)
+                ),
+            },
+            cmd::command_list::Commands::RenderMime { enc, q } => {
+                match transfer::encoded_words(&vecbuff, &enc, encoding_name(&enc), q) {
+                    Ok(words) => println!("{}", words.join("\n ")),
+                    Err(pos) => println!(
+                        "Unable to represent the elements in {} : {}",
+                        encoding_name(&enc),
+                        list_positions(&vecbuff, &pos)
+                    ),
+                }
+            }
             cmd::command_list::Commands::Valid => {
                 println!(
                     "{}!",
@@ -161,38 +292,577 @@ fn main() {
                     }
                 )
             }
+            cmd::command_list::Commands::BufferList => {
+                for (name, len, active) in buffers.list(&vecbuff) {
+                    println!("{} {} ({})", if active { '*' } else { ' ' }, name, len);
+                }
+            }
+            cmd::command_list::Commands::BufferSwitch { name } => {
+                if !buffers.switch(&name, &mut vecbuff) {
+                    println!("There is no buffer named {}", name);
+                }
+            }
+            cmd::command_list::Commands::BufferNew { name } => {
+                if !buffers.create(&name) {
+                    println!("The buffer {} already exists", name);
+                }
+            }
+            cmd::command_list::Commands::BufferCopy { src, dst } => {
+                if !buffers.copy(&src, &dst, &mut vecbuff) {
+                    println!("There is no buffer named {}", src);
+                }
+            }
+            cmd::command_list::Commands::Yank { reg, start, end } => {
+                if end > vecbuff.len() {
+                    println!(
+                        "Unable to yank up to element number {}, as buffer only contains {} {}",
+                        end,
+                        vecbuff.len(),
+                        if vecbuff.len() > 1 {
+                            "elements"
+                        } else {
+                            "element"
+                        }
+                    );
+                } else {
+                    registers.insert(reg, vecbuff[start..end].to_vec());
+                }
+            }
+            cmd::command_list::Commands::Put { reg, pos } => {
+                let Some(content) = registers.get(&reg) else {
+                    println!("The register {} is empty", reg);
+                    continue;
+                };
+                let pos = pos.unwrap_or(vecbuff.len()).min(vecbuff.len());
+                vecbuff.splice(pos..pos, content.iter().copied());
+            }
+            cmd::command_list::Commands::Compare(src) => {
+                let Some(other) = load_source(&src, &buffers, &vecbuff) else {
+                    continue;
+                };
+                let cmp = compare::compare(&vecbuff, &other);
+                let verdict = |x: bool| if x { "equivalent" } else { "not equivalent" };
+                println!(
+                    "Binary   : {}",
+                    if cmp.binary { "equal" } else { "different" }
+                );
+                match cmp.forms {
+                    Some(forms) => {
+                        println!("NFC      : {}", verdict(forms.nfc));
+                        println!("NFD      : {}", verdict(forms.nfd));
+                        println!("NFKC     : {}", verdict(forms.nfkc));
+                        println!("NFKD     : {}", verdict(forms.nfkd));
+                        println!("Caseless : {}", verdict(forms.caseless));
+                    }
+                    None => println!("Normalization is unavailable on invalid codepoints"),
+                }
+                if let Some(pos) = cmp.first_diff {
+                    println!(
+                        "First difference at {} : {} <> {}",
+                        pos,
+                        codepoint::notation_at(&vecbuff, pos),
+                        codepoint::notation_at(&other, pos)
+                    );
+                }
+            }
+            cmd::command_list::Commands::DecodeBytes { enc, bytes } => {
+                let mut decoded = codec::decode(&bytes, &enc);
+                report_decode_errors(&decoded.errors);
+                vecbuff.append(&mut decoded.codepoints);
+            }
+            cmd::command_list::Commands::DecodeMime(parts) => {
+                for (enc, bytes) in parts {
+                    let mut decoded = codec::decode(&bytes, &enc);
+                    report_decode_errors(&decoded.errors);
+                    vecbuff.append(&mut decoded.codepoints);
+                }
+            }
+            cmd::command_list::Commands::DecodeUnits(units) => {
+                let (mut decoded, unpaired) = codec::decode_utf16_units(&units);
+                for idx in unpaired {
+                    println!("Unpaired surrogate at unit {} : {:04X}", idx, units[idx]);
+                }
+                vecbuff.append(&mut decoded);
+            }
+            cmd::command_list::Commands::MojibakeDetect => {
+                let found = mojibake::detect(&vecbuff);
+                if found.is_empty() {
+                    println!("No wrong decoding detected");
+                }
+                for repair in found {
+                    println!(
+                        "{} decoded as {} : {}",
+                        encoding_name(&repair.right),
+                        encoding_name(&repair.wrong),
+                        preview(&repair.result)
+                    );
+                }
+            }
+            cmd::command_list::Commands::MojibakeRepair { wrong, right } => {
+                let Some(result) = mojibake::repair(&vecbuff, &wrong, &right) else {
+                    println!(
+                        "The buffer is not {} decoded as {}",
+                        encoding_name(&right),
+                        encoding_name(&wrong)
+                    );
+                    continue;
+                };
+                println!("Preview : {}", preview(&result));
+                println!("Replace the buffer? [y/N]");
+                match stdreader() {
+                    Ok(ans) if ans.trim().eq_ignore_ascii_case("y") => vecbuff = result,
+                    _ => println!("The buffer is left unchanged"),
+                }
+            }
+            cmd::command_list::Commands::Transform { kind, range } => {
+                let (start, end) = range.unwrap_or((0, vecbuff.len()));
+                if end > vecbuff.len() {
+                    println!(
+                        "Unable to transform up to element number {}, as buffer only contains {} {}",
+                        end,
+                        vecbuff.len(),
+                        if vecbuff.len() > 1 {
+                            "elements"
+                        } else {
+                            "element"
+                        }
+                    );
+                    continue;
+                }
+                let (result, failed) = transform::apply(&vecbuff, start, end, &kind);
+                if !failed.is_empty() {
+                    println!(
+                        "Unable to transform the elements : {}",
+                        list_positions(&vecbuff, &failed)
+                    );
+                }
+                vecbuff.splice(start..end, result);
+            }
+            cmd::command_list::Commands::IdnaAscii => match idn::to_ascii(&vecbuff) {
+                Ok(domain) => {
+                    println!("{}", domain);
+                    vecbuff = domain.chars().map(|x| x as u32).collect();
+                }
+                Err(err) => report_idna_error(&err),
+            },
+            cmd::command_list::Commands::IdnaUnicode(domain) => {
+                let domain = match domain {
+                    Some(x) => x,
+                    None => match render_buffer(&vecbuff) {
+                        Some(x) => x.into_iter().collect(),
+                        None => {
+                            println!("Unable to decode the buffer, as it is not in a valid state");
+                            continue;
+                        }
+                    },
+                };
+                match idn::to_unicode(&domain) {
+                    Ok(result) => {
+                        println!("{}", preview(&result));
+                        vecbuff = result;
+                    }
+                    Err(err) => report_idna_error(&err),
+                }
+            }
+            cmd::command_list::Commands::HangulCompose { lead, vowel, tail } => {
+                let jamo = hangul::Jamo { lead, vowel, tail };
+                match (hangul::compose(jamo), hangul::arithmetic(jamo)) {
+                    (Some(syllable), Some(steps)) => {
+                        println!("{}", steps);
+                        vecbuff.push(syllable);
+                    }
+                    _ => println!(
+                        "Unable to compose the syllable, as the lead must be below {}, the vowel below {} and the tail below {}",
+                        hangul::LEAD_COUNT,
+                        hangul::VOWEL_COUNT,
+                        hangul::TAIL_COUNT
+                    ),
+                }
+            }
+            cmd::command_list::Commands::HangulRoman(text) => match hangul::parse_roman(&text) {
+                Some(syllables) => {
+                    println!("{}", preview(&syllables));
+                    vecbuff.extend(syllables);
+                }
+                None => println!("Unable to read {:?} as romanized Hangul syllables", text),
+            },
+            cmd::command_list::Commands::HangulDecompose { pos } => {
+                if pos >= vecbuff.len() {
+                    println!(
+                        "Unable to decompose element number {}, as buffer only contains {} {}",
+                        pos,
+                        vecbuff.len(),
+                        if vecbuff.len() > 1 {
+                            "elements"
+                        } else {
+                            "element"
+                        }
+                    );
+                    continue;
+                }
+                let Some(jamo) = hangul::decompose(vecbuff[pos]) else {
+                    println!(
+                        "Unable to decompose element number {}, as {} is not a Hangul syllable",
+                        pos,
+                        codepoint::notation(vecbuff[pos])
+                    );
+                    continue;
+                };
+                if let Some(steps) = hangul::arithmetic(jamo) {
+                    println!("{}", steps);
+                }
+                let parts = hangul::conjoining(jamo);
+                for &part in &parts {
+                    println!("{}", codepoint::describe(part));
+                }
+                vecbuff.splice(pos..pos + 1, parts);
+            }
+            cmd::command_list::Commands::VariationList => {
+                let found = variation::positions(&vecbuff);
+                if found.is_empty() {
+                    println!("No character of the buffer has variation sequences");
+                }
+                for pos in found {
+                    let variants = variation::variants(vecbuff[pos])
+                        .iter()
+                        .map(|(selector, name)| {
+                            format!("{} {}", codepoint::notation(*selector), name)
+                        })
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    let selected = match vecbuff.get(pos + 1) {
+                        Some(&x) if variation::is_selector(x) => {
+                            format!(" (followed by {})", codepoint::notation(x))
+                        }
+                        _ => String::new(),
+                    };
+                    println!(
+                        "{:>5} {} : {}{}",
+                        pos,
+                        codepoint::describe(vecbuff[pos]),
+                        variants,
+                        selected
+                    );
+                }
+            }
+            cmd::command_list::Commands::Confusable(src) => {
+                let Some(text) = render_buffer(&vecbuff) else {
+                    println!("Unable to decode the buffer, as it is not in a valid state");
+                    continue;
+                };
+                let Some(src) = src else {
+                    report_confusable(&text);
+                    continue;
+                };
+                let Some(other) = load_source(&src, &buffers, &vecbuff) else {
+                    continue;
+                };
+                let Some(other) = render_buffer(&other) else {
+                    println!("Unable to decode the other sequence, as it is not in a valid state");
+                    continue;
+                };
+                match confusable::first_difference(&text, &other) {
+                    None => {
+                        println!(
+                            "Confusable, with the skeleton {}",
+                            preview(&to_codepoints(&confusable::skeleton(&text)))
+                        );
+                        println!("Active buffer : {}", list_mapped(&text));
+                        println!("Other sequence : {}", list_mapped(&other));
+                    }
+                    Some((left, right)) => println!(
+                        "Not confusable, the skeletons differ from element number {} of the active buffer and {} of the other sequence",
+                        left, right
+                    ),
+                }
+            }
+            cmd::command_list::Commands::BidiCheck => {
+                let issues = reveal::bidi_issues(&vecbuff);
+                if issues.is_empty() {
+                    println!("The bidirectional controls are balanced");
+                }
+                for issue in issues {
+                    let pos = issue.position();
+                    let reason = match issue {
+                        reveal::BidiIssue::Unterminated(_) => {
+                            "not closed before the end of the line"
+                        }
+                        reveal::BidiIssue::Unmatched(_) => "no matching opening control",
+                    };
+                    println!(
+                        "{:>5} {} : {}",
+                        pos,
+                        codepoint::describe(vecbuff[pos]),
+                        reason
+                    );
+                }
+            }
+            cmd::command_list::Commands::EmojiModifier { base, tone } => {
+                if !emoji::is_modifier_base(base) {
+                    println!(
+                        "Unable to apply the skin tone, as {} does not take a modifier",
+                        codepoint::notation(base)
+                    );
+                    continue;
+                }
+                append_emoji(&mut vecbuff, emoji::modifier(base, &tone));
+            }
+            cmd::command_list::Commands::EmojiZwj(elements) => {
+                append_emoji(&mut vecbuff, emoji::zwj(&elements));
+            }
+            cmd::command_list::Commands::EmojiFlag(region) => {
+                append_emoji(&mut vecbuff, emoji::flag(&region));
+            }
+            cmd::command_list::Commands::EmojiSubdivision(code) => {
+                append_emoji(&mut vecbuff, emoji::subdivision(&code));
+            }
+            cmd::command_list::Commands::EmojiKeycap(key) => {
+                append_emoji(&mut vecbuff, emoji::keycap(key));
+            }
+            cmd::command_list::Commands::EmojiCheck => {
+                let found = emoji::sequences(&vecbuff);
+                for seq in &found {
+                    println!(
+                        "{:>5} {:>5} {} : {}",
+                        seq.start,
+                        seq.end,
+                        preview(&vecbuff[seq.start..seq.end]),
+                        seq.kind.map_or("not RGI", |x| x.name())
+                    );
+                }
+                let invalid = found.iter().filter(|x| x.kind.is_none()).count();
+                println!(
+                    "{} emoji {}, {} not RGI",
+                    found.len(),
+                    if found.len() > 1 {
+                        "sequences"
+                    } else {
+                        "sequence"
+                    },
+                    invalid
+                );
+            }
+            cmd::command_list::Commands::Diff(src) => {
+                let Some(other) = load_source(&src, &buffers, &vecbuff) else {
+                    continue;
+                };
+                for edit in diff::diff(&vecbuff, &other) {
+                    match edit {
+                        diff::Edit::Keep { left, right, cp } => {
+                            println!("  {:>5} {:>5} {}", left, right, codepoint::describe(cp))
+                        }
+                        diff::Edit::Delete { left, cp } => {
+                            println!("- {:>5} {:>5} {}", left, "", codepoint::describe(cp))
+                        }
+                        diff::Edit::Insert { right, cp } => {
+                            println!("+ {:>5} {:>5} {}", "", right, codepoint::describe(cp))
+                        }
+                        diff::Edit::Change {
+                            left,
+                            right,
+                            from,
+                            to,
+                        } => println!(
+                            "~ {:>5} {:>5} {} -> {}",
+                            left,
+                            right,
+                            codepoint::describe(from),
+                            codepoint::describe(to)
+                        ),
+                    }
+                }
+            }
         };
     }
 }
 
-fn render_buffer(vecbuff: &Vec<u32>) -> Option<Vec<char>> {
-    //vecbuff.iter().map(|&x| char::from_u32(x)?)
-    vecbuff.iter().try_fold(Vec::new(), |mut acc, &x| {
-        acc.push(char::from_u32(x)?);
-        Some(acc)
-    })
+fn read_file(file: &str) -> Option<Vec<u32>> {
+    match std::fs::read_to_string(file) {
+        Ok(x) => Some(x.chars().map(|x| x as u32).collect()),
+        Err(_) => {
+            println!("Unable to open file {}", file);
+            None
+        }
+    }
 }
 
-fn bitsplitter(inp: &Vec<u32>, big_endian: bool) -> Vec<u8> {
-    inp.iter()
-        .flat_map(if big_endian {
-            |val| {
-                vec![
-                    ((val >> 24) as u32 & 0xff) as u8,
-                    ((val >> 16) as u32 & 0xff) as u8,
-                    ((val >> 8) as u32 & 0xff) as u8,
-                    ((val >> 0) as u32 & 0xff) as u8,
-                ]
+/// Obtain the sequence from the source, the error is reported to the user
+fn load_source(
+    src: &cmd::command_list::Source,
+    buffers: &buffers::BufferSet,
+    vecbuff: &[u32],
+) -> Option<Vec<u32>> {
+    match src {
+        cmd::command_list::Source::Buffer(name) => match buffers.get(name, vecbuff) {
+            Some(x) => Some(x.to_vec()),
+            None => {
+                println!("There is no buffer named {}", name);
+                None
             }
+        },
+        cmd::command_list::Source::File(file) => read_file(file),
+    }
+}
+
+fn report_decode_errors(errors: &[codec::DecodeError]) {
+    for err in errors {
+        println!(
+            "{} sequence at byte offset {} : {}",
+            if err.truncated {
+                "Truncated"
+            } else {
+                "Invalid"
+            },
+            err.offset,
+            err.bytes
+                .iter()
+                .map(|x| format!("{:02X}", x))
+                .collect::<Vec<String>>()
+                .join(" ")
+        );
+    }
+}
+
+fn report_idna_error(err: &idn::Error) {
+    match err {
+        idn::Error::InvalidCodepoint => {
+            println!("Unable to convert the buffer, as it is not in a valid state")
+        }
+        idn::Error::Uts46(checks) => println!("UTS #46 processing failed : {}", checks),
+        idn::Error::Context(pos) => {
+            println!(
+                "Contextual rule violated at the elements of the mapped domain : {}",
+                pos.iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+    }
+}
+
+/// Print the skeleton, the scripts and the confusable strings of the text
+fn report_confusable(text: &[char]) {
+    println!(
+        "Skeleton : {}",
+        preview(&to_codepoints(&confusable::skeleton(text)))
+    );
+    println!("Mapped : {}", list_mapped(text));
+    println!("Restriction level : {:?}", confusable::restriction(text));
+    let scripts = confusable::scripts(text)
+        .iter()
+        .map(|(script, pos)| {
+            format!(
+                "{} ({})",
+                script.full_name(),
+                pos.iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    println!(
+        "{} : {}",
+        if confusable::is_mixed(text) {
+            "Mixed-script"
         } else {
-            |val| {
-                vec![
-                    ((val >> 0) as u32 & 0xff) as u8,
-                    ((val >> 8) as u32 & 0xff) as u8,
-                    ((val >> 16) as u32 & 0xff) as u8,
-                    ((val >> 24) as u32 & 0xff) as u8,
-                ]
-            }
+            "Single-script"
+        },
+        scripts
+    );
+    for (kind, found) in [
+        ("Whole-script", confusable::whole_script(text)),
+        ("Mixed-script", confusable::mixed_script(text)),
+    ] {
+        for sub in found {
+            println!(
+                "{} confusable with {} : {}, replacing the elements {}",
+                kind,
+                sub.script.full_name(),
+                preview(&to_codepoints(&sub.text)),
+                sub.positions
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
+}
+
+/// List the characters mapped to build the skeleton, or `none`
+fn list_mapped(text: &[char]) -> String {
+    let mapped = confusable::mapped(text);
+    if mapped.is_empty() {
+        return "none".to_string();
+    }
+    mapped
+        .iter()
+        .map(|(pos, piece)| {
+            format!(
+                "{} ({}) -> {:?}",
+                pos,
+                codepoint::notation(text[*pos] as u32),
+                piece
+            )
         })
-        .collect()
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn to_codepoints(text: &[char]) -> Vec<u32> {
+    text.iter().map(|&x| x as u32).collect()
+}
+
+/// Append the emoji sequence to the buffer, and report whether it is in the RGI set
+fn append_emoji(buff: &mut Vec<u32>, seq: Vec<u32>) {
+    println!("{} : {}", preview(&seq), emoji::status(&seq));
+    buff.extend(seq);
+}
+
+/// List the positions together with the codepoint of the buffer
+fn list_positions(buff: &[u32], pos: &[usize]) -> String {
+    pos.iter()
+        .map(|&x| format!("{} ({})", x, codepoint::notation(buff[x])))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Name of the encoding to be shown to the user
+fn encoding_name(enc: &cmd::command_list::EncodingType) -> &'static str {
+    match enc {
+        cmd::command_list::EncodingType::UTF8 => "UTF-8",
+        cmd::command_list::EncodingType::UTF16 => "UTF-16BE",
+        cmd::command_list::EncodingType::UTF16LE => "UTF-16LE",
+        cmd::command_list::EncodingType::UTF32 => "UTF-32BE",
+        cmd::command_list::EncodingType::UTF32LE => "UTF-32LE",
+        cmd::command_list::EncodingType::UTF7 => "UTF-7",
+        cmd::command_list::EncodingType::IMAPUTF7 => "IMAP modified UTF-7",
+        cmd::command_list::EncodingType::SCSU => "SCSU",
+        cmd::command_list::EncodingType::BOCU1 => "BOCU-1",
+        cmd::command_list::EncodingType::Latin1 => "ISO-8859-1",
+        cmd::command_list::EncodingType::Legacy(enc) => enc.name(),
+    }
+}
+
+/// Render the codepoints for a preview, or list them when they are not valid
+fn preview(buff: &[u32]) -> String {
+    match render_buffer(buff) {
+        Some(x) => format!("{:?}", x.iter().collect::<String>()),
+        None => buff
+            .iter()
+            .map(|&x| codepoint::notation(x))
+            .collect::<Vec<String>>()
+            .join(" "),
+    }
+}
+
+fn render_buffer(vecbuff: &[u32]) -> Option<Vec<char>> {
+    //vecbuff.iter().map(|&x| char::from_u32(x)?)
+    vecbuff.iter().try_fold(Vec::new(), |mut acc, &x| {
+        acc.push(char::from_u32(x)?);
+        Some(acc)
+    })
 }
@@ -0,0 +1,67 @@
+/*! Detection and repair of the wrongly decoded text
+
+A mojibake is produced when the bytes of a text are decoded with another encoding
+than the one used to encode them. As long as the wrong decoding did not lose any byte,
+it is reversed by encoding the text back with the wrong encoding, and decoding the
+bytes with the right encoding.
+ */
+
+#[cfg(test)]
+mod testing;
+
+use crate::cmd::EncodingType;
+use crate::codec;
+
+/// Candidate pair of a wrong decoding
+pub struct Repair {
+    pub wrong: EncodingType,
+    pub right: EncodingType,
+    pub result: Vec<u32>,
+}
+
+/// Wrong encodings tried by the detection, in the order of likelihood
+fn candidates() -> [EncodingType; 5] {
+    [
+        EncodingType::Legacy(encoding_rs::WINDOWS_1252),
+        EncodingType::Latin1,
+        EncodingType::Legacy(encoding_rs::WINDOWS_1250),
+        EncodingType::Legacy(encoding_rs::WINDOWS_1251),
+        EncodingType::Legacy(encoding_rs::ISO_8859_15),
+    ]
+}
+
+/** Reverse the wrong decoding
+
+Return None when the buffer is not representable in the wrong encoding,
+or when the bytes are not valid in the right encoding.
+ */
+pub fn repair(buff: &[u32], wrong: &EncodingType, right: &EncodingType) -> Option<Vec<u32>> {
+    let bytes = codec::encode(buff, wrong).ok()?;
+    let decoded = codec::decode(&bytes, right);
+    if !decoded.errors.is_empty() {
+        return None;
+    }
+    Some(decoded.codepoints)
+}
+
+/** Detect the likely wrong decoding of a UTF-8 text
+
+A candidate is kept when the repair succeeds and joins at least one multi-byte sequence,
+as a text which is only ASCII is left unchanged by any of the candidates.
+ */
+pub fn detect(buff: &[u32]) -> Vec<Repair> {
+    candidates()
+        .into_iter()
+        .filter_map(|wrong| {
+            let result = repair(buff, &wrong, &EncodingType::UTF8)?;
+            if result.len() >= buff.len() {
+                return None;
+            }
+            Some(Repair {
+                wrong,
+                right: EncodingType::UTF8,
+                result,
+            })
+        })
+        .collect()
+}
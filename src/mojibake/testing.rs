@@ -0,0 +1,46 @@
+/*! Testing submodule for the mojibake module
+ */
+
+use super::*;
+use crate::testing::codepoints;
+
+#[test]
+fn repair_windows_1252() {
+    assert_eq!(
+        repair(
+            &codepoints("cafÃ© â‚¬"),
+            &EncodingType::Legacy(encoding_rs::WINDOWS_1252),
+            &EncodingType::UTF8
+        ),
+        Some(codepoints("café €"))
+    );
+}
+
+#[test]
+fn repair_latin1() {
+    // U+20AC encoded in UTF-8 and decoded as ISO-8859-1
+    assert_eq!(
+        repair(
+            &[0xE2, 0x82, 0xAC],
+            &EncodingType::Latin1,
+            &EncodingType::UTF8
+        ),
+        Some(vec![0x20AC])
+    );
+    assert_eq!(
+        repair(&[0x20AC], &EncodingType::Latin1, &EncodingType::UTF8),
+        None
+    );
+}
+
+#[test]
+fn detect_mojibake() {
+    let found = detect(&codepoints("cafÃ©"));
+    assert_eq!(
+        found[0].wrong,
+        EncodingType::Legacy(encoding_rs::WINDOWS_1252)
+    );
+    assert_eq!(found[0].result, codepoints("café"));
+    assert!(detect(&codepoints("café")).is_empty());
+    assert!(detect(&codepoints("plain")).is_empty());
+}
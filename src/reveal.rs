@@ -0,0 +1,172 @@
+/*! Revealing of the invisible characters and checking of the bidirectional controls
+
+The default ignorable codepoints, the bidirectional controls and the control characters
+are written as a visible placeholder, e.g. `⟨ZWJ⟩`, so the text can be reviewed as it is stored.
+The bidirectional embeddings, overrides and isolates are checked the way the Trojan Source
+detectors do: each of them must be closed within the same paragraph, and each closing control
+must match an opening one.
+ */
+
+#[cfg(test)]
+mod testing;
+
+const LRE: u32 = 0x202A;
+const RLE: u32 = 0x202B;
+const PDF: u32 = 0x202C;
+const LRO: u32 = 0x202D;
+const RLO: u32 = 0x202E;
+const LRI: u32 = 0x2066;
+const RLI: u32 = 0x2067;
+const FSI: u32 = 0x2068;
+const PDI: u32 = 0x2069;
+
+/// Unbalanced bidirectional control, by position
+#[derive(Debug, PartialEq, Eq)]
+pub enum BidiIssue {
+    /// Embedding, override or isolate still open at the end of the paragraph
+    Unterminated(usize),
+    /// PDF or PDI without the matching opening control
+    Unmatched(usize),
+}
+
+impl BidiIssue {
+    pub fn position(&self) -> usize {
+        match self {
+            BidiIssue::Unterminated(pos) | BidiIssue::Unmatched(pos) => *pos,
+        }
+    }
+}
+
+/// Whether the codepoint has the Default_Ignorable_Code_Point property
+pub fn is_default_ignorable(cp: u32) -> bool {
+    matches!(
+        cp,
+        0x00AD
+            | 0x034F
+            | 0x061C
+            | 0x115F..=0x1160
+            | 0x17B4..=0x17B5
+            | 0x180B..=0x180F
+            | 0x200B..=0x200F
+            | 0x202A..=0x202E
+            | 0x2060..=0x206F
+            | 0x3164
+            | 0xFE00..=0xFE0F
+            | 0xFEFF
+            | 0xFFA0
+            | 0xFFF0..=0xFFF8
+            | 0x1BCA0..=0x1BCA3
+            | 0x1D173..=0x1D17A
+            | 0xE0000..=0xE0FFF
+    )
+}
+
+fn abbreviation(cp: u32) -> Option<String> {
+    Some(
+        match cp {
+            0x00AD => "SHY",
+            0x034F => "CGJ",
+            0x061C => "ALM",
+            0x180E => "MVS",
+            0x200B => "ZWSP",
+            0x200C => "ZWNJ",
+            0x200D => "ZWJ",
+            0x200E => "LRM",
+            0x200F => "RLM",
+            0x2028 => "LS",
+            0x2029 => "PS",
+            LRE => "LRE",
+            RLE => "RLE",
+            PDF => "PDF",
+            LRO => "LRO",
+            RLO => "RLO",
+            0x2060 => "WJ",
+            LRI => "LRI",
+            RLI => "RLI",
+            FSI => "FSI",
+            PDI => "PDI",
+            0xFEFF => "BOM",
+            0x180B..=0x180D => return Some(format!("FVS{}", cp - 0x180A)),
+            0x180F => "FVS4",
+            0xFE00..=0xFE0F => return Some(format!("VS{}", cp - 0xFDFF)),
+            0xE0100..=0xE01EF => return Some(format!("VS{}", cp - 0xE00EF)),
+            _ => return None,
+        }
+        .to_string(),
+    )
+}
+
+/// Placeholder of the codepoint, return None when the codepoint is visible
+pub fn placeholder(cp: u32) -> Option<String> {
+    let hidden = match char::from_u32(cp) {
+        Some('\n' | '\t') => false,
+        Some(chr) => chr.is_control() || is_default_ignorable(cp) || cp == 0x2028 || cp == 0x2029,
+        None => true,
+    };
+    if !hidden {
+        return None;
+    }
+    Some(format!(
+        "⟨{}⟩",
+        abbreviation(cp).unwrap_or_else(|| format!("U+{:04X}", cp))
+    ))
+}
+
+/// Render the buffer with the placeholders in place of the invisible codepoints
+pub fn reveal(buff: &[u32]) -> String {
+    buff.iter()
+        .map(|&cp| match placeholder(cp) {
+            Some(text) => text,
+            None => char::from_u32(cp).map(String::from).unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn is_embedding(cp: u32) -> bool {
+    matches!(cp, LRE | RLE | LRO | RLO)
+}
+
+fn is_isolate(cp: u32) -> bool {
+    matches!(cp, LRI | RLI | FSI)
+}
+
+/// Whether the codepoint ends the paragraph, following the bidi class B
+fn is_paragraph_end(cp: u32) -> bool {
+    matches!(cp, 0x0A | 0x0D | 0x1C..=0x1E | 0x85 | 0x2029)
+}
+
+/** Find the unbalanced bidirectional controls, sorted by position
+
+The PDF only closes the last embedding or override when no isolate was opened after it,
+while the PDI closes the last isolate, together with the embeddings and overrides opened after it,
+which are reported as unterminated.
+ */
+pub fn bidi_issues(buff: &[u32]) -> Vec<BidiIssue> {
+    let mut out = Vec::new();
+    let mut open: Vec<usize> = Vec::new();
+    for (pos, &cp) in buff.iter().enumerate() {
+        if is_embedding(cp) || is_isolate(cp) {
+            open.push(pos);
+        } else if cp == PDF {
+            match open.last() {
+                Some(&last) if is_embedding(buff[last]) => {
+                    open.pop();
+                }
+                _ => out.push(BidiIssue::Unmatched(pos)),
+            }
+        } else if cp == PDI {
+            match open.iter().rposition(|&x| is_isolate(buff[x])) {
+                Some(idx) => {
+                    out.extend(open.drain(idx + 1..).map(BidiIssue::Unterminated));
+                    open.pop();
+                }
+                None => out.push(BidiIssue::Unmatched(pos)),
+            }
+        } else if is_paragraph_end(cp) {
+            out.extend(open.drain(..).map(BidiIssue::Unterminated));
+        }
+    }
+    out.extend(open.drain(..).map(BidiIssue::Unterminated));
+    out.sort_by_key(|x| x.position());
+    out
+}
@@ -0,0 +1,57 @@
+/*! Testing submodule for the reveal module
+ */
+
+use super::*;
+use crate::testing::codepoints;
+
+#[test]
+fn placeholders() {
+    assert_eq!(placeholder('a' as u32), None);
+    assert_eq!(placeholder('\n' as u32), None);
+    assert_eq!(placeholder(0x200D), Some("⟨ZWJ⟩".to_string()));
+    assert_eq!(placeholder(0xFEFF), Some("⟨BOM⟩".to_string()));
+    assert_eq!(placeholder(0x00AD), Some("⟨SHY⟩".to_string()));
+    assert_eq!(placeholder(0xFE0F), Some("⟨VS16⟩".to_string()));
+    assert_eq!(placeholder(0xE0100), Some("⟨VS17⟩".to_string()));
+    assert_eq!(placeholder(0x180B), Some("⟨FVS1⟩".to_string()));
+    assert_eq!(placeholder(0x115F), Some("⟨U+115F⟩".to_string()));
+    assert_eq!(placeholder(0x1B), Some("⟨U+001B⟩".to_string()));
+    assert_eq!(placeholder(0xD800), Some("⟨U+D800⟩".to_string()));
+}
+#[test]
+fn default_ignorable() {
+    assert!(is_default_ignorable(0x200B));
+    assert!(is_default_ignorable(0xE0041));
+    assert!(!is_default_ignorable(0x00A0));
+    assert!(!is_default_ignorable(0x2028));
+}
+#[test]
+fn reveal_text() {
+    let buff = codepoints("a\u{200B}b\u{202E}c\u{202C}\nd");
+    assert_eq!(reveal(&buff), "a⟨ZWSP⟩b⟨RLO⟩c⟨PDF⟩\nd");
+}
+#[test]
+fn balanced_controls() {
+    let buff = [RLO, 0x61, PDF, LRI, RLE, 0x62, PDF, PDI];
+    assert!(bidi_issues(&buff).is_empty());
+}
+#[test]
+fn unterminated_controls() {
+    let buff = [0x61, RLO, 0x62, 0x0A, RLI, 0x63];
+    assert_eq!(
+        bidi_issues(&buff),
+        vec![BidiIssue::Unterminated(1), BidiIssue::Unterminated(4)]
+    );
+    let buff = [RLI, LRE, 0x61, PDI];
+    assert_eq!(bidi_issues(&buff), vec![BidiIssue::Unterminated(1)]);
+}
+#[test]
+fn unmatched_controls() {
+    let buff = [PDF, 0x61, PDI];
+    assert_eq!(
+        bidi_issues(&buff),
+        vec![BidiIssue::Unmatched(0), BidiIssue::Unmatched(2)]
+    );
+    let buff = [LRE, RLI, PDF, PDI, PDF];
+    assert_eq!(bidi_issues(&buff), vec![BidiIssue::Unmatched(2)]);
+}
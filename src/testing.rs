@@ -0,0 +1,7 @@
+/*! Helpers shared by the testing submodules
+ */
+
+/// Codepoints of the text, as stored in the buffer
+pub fn codepoints(text: &str) -> Vec<u32> {
+    text.chars().map(|x| x as u32).collect()
+}
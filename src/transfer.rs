@@ -0,0 +1,211 @@
+/*! Transfer formats of the encoded text
+
+The bytes of an encoded text are written in a restricted set of ASCII characters
+before being placed in URLs (percent-encoding) and in the headers of an email (RFC 2047 encoded-word).
+ */
+
+#[cfg(test)]
+mod testing;
+
+use crate::cmd::EncodingType;
+use crate::codec;
+use base64::Engine;
+
+/// Maximum length of an encoded-word, including the delimiters
+pub const WORD_LENGTH: usize = 75;
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+/** Write the bytes with percent-encoding
+
+The unreserved characters of RFC 3986 are kept as is.
+In a path the `/` is also kept, to preserve the segments.
+In a query the `application/x-www-form-urlencoded` form is used, where the space is written as `+`.
+ */
+pub fn percent_encode(bytes: &[u8], query: bool) -> String {
+    let mut out = String::new();
+    for &byte in bytes {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(byte as char)
+            }
+            b'/' if !query => out.push('/'),
+            b' ' if query => out.push('+'),
+            _ => {
+                out.push('%');
+                out.push(HEX[(byte >> 4) as usize] as char);
+                out.push(HEX[(byte & 0xF) as usize] as char);
+            }
+        }
+    }
+    out
+}
+
+/** Read the bytes back from the percent-encoding
+
+In a query the `+` is read as a space.
+Return None when a `%` is not followed by two hexadecimal digits.
+ */
+pub fn percent_decode(text: &str, query: bool) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut itr = text.bytes();
+    while let Some(byte) = itr.next() {
+        match byte {
+            b'%' => {
+                let digits = [itr.next()?, itr.next()?];
+                out.push(u8::from_str_radix(std::str::from_utf8(&digits).ok()?, 16).ok()?);
+            }
+            b'+' if query => out.push(b' '),
+            _ => out.push(byte),
+        }
+    }
+    Some(out)
+}
+
+/// Write the bytes with the `Q` encoding, restricted to the characters allowed in a phrase
+fn q_encode(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for &byte in bytes {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'!' | b'*' | b'+' | b'-' | b'/' => {
+                out.push(byte as char)
+            }
+            b' ' => out.push('_'),
+            _ => {
+                out.push('=');
+                out.push(HEX[(byte >> 4) as usize] as char);
+                out.push(HEX[(byte & 0xF) as usize] as char);
+            }
+        }
+    }
+    out
+}
+
+fn q_decode(text: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut itr = text.bytes();
+    while let Some(byte) = itr.next() {
+        match byte {
+            b'=' => {
+                let digits = [itr.next()?, itr.next()?];
+                out.push(u8::from_str_radix(std::str::from_utf8(&digits).ok()?, 16).ok()?);
+            }
+            b'_' => out.push(b' '),
+            _ => out.push(byte),
+        }
+    }
+    Some(out)
+}
+
+fn word(charset: &str, bytes: &[u8], q: bool) -> String {
+    if q {
+        format!("=?{}?Q?{}?=", charset, q_encode(bytes))
+    } else {
+        format!(
+            "=?{}?B?{}?=",
+            charset,
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        )
+    }
+}
+
+/** Write the buffer as RFC 2047 encoded-words
+
+The buffer is split between the codepoints, so that each word stays within [WORD_LENGTH].
+Each codepoint is kept in a single word, as the words are decoded separately.
+Return the positions of the unrepresentable codepoints on failure.
+ */
+pub fn encoded_words(
+    buff: &[u32],
+    enc: &EncodingType,
+    charset: &str,
+    q: bool,
+) -> Result<Vec<String>, Vec<usize>> {
+    codec::encode(buff, enc)?;
+    let mut words = Vec::new();
+    let mut start = 0;
+    while start < buff.len() {
+        let mut end = start + 1;
+        let mut current = word(charset, &codec::encode(&buff[start..end], enc)?, q);
+        while end < buff.len() {
+            let next = word(charset, &codec::encode(&buff[start..end + 1], enc)?, q);
+            if next.len() > WORD_LENGTH {
+                break;
+            }
+            current = next;
+            end += 1;
+        }
+        words.push(current);
+        start = end;
+    }
+    Ok(words)
+}
+
+/// Part of a header containing encoded-words
+#[derive(Debug, PartialEq, Eq)]
+pub enum Segment {
+    /// Text outside of the encoded-words
+    Text(String),
+    /// Decoded bytes of an encoded-word, with the name of the charset
+    Word { charset: String, bytes: Vec<u8> },
+}
+
+/// Read a single encoded-word, with the delimiters
+fn parse_word(text: &str) -> Option<Segment> {
+    let inner = text.strip_prefix("=?")?.strip_suffix("?=")?;
+    let mut parts = inner.splitn(3, '?');
+    let charset = parts.next()?;
+    // The language of RFC 2231 is ignored
+    let charset = charset.split_once('*').map_or(charset, |x| x.0);
+    let encoding = parts.next()?;
+    let data = parts.next()?;
+    if charset.is_empty() || data.contains('?') || data.contains(char::is_whitespace) {
+        return None;
+    }
+    let bytes = match encoding {
+        "B" | "b" => base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .ok()?,
+        "Q" | "q" => q_decode(data)?,
+        _ => return None,
+    };
+    Some(Segment::Word {
+        charset: charset.to_string(),
+        bytes,
+    })
+}
+
+/** Split a header into its encoded-words and the text around them
+
+The whitespace between two adjacent encoded-words is dropped.
+The tokens which look like an encoded-word but are malformed are kept as text.
+ */
+pub fn parse_encoded_words(text: &str) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    let mut pending = String::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let token_len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let (token, tail) = rest.split_at(token_len);
+        let space_len = tail
+            .find(|c: char| !c.is_whitespace())
+            .unwrap_or(tail.len());
+        let (space, tail) = tail.split_at(space_len);
+        match parse_word(token) {
+            Some(word) => {
+                let after_word = matches!(segments.last(), Some(Segment::Word { .. }));
+                if !(after_word && pending.chars().all(char::is_whitespace)) {
+                    segments.push(Segment::Text(std::mem::take(&mut pending)));
+                }
+                pending.clear();
+                segments.push(word);
+            }
+            None => pending.push_str(token),
+        }
+        pending.push_str(space);
+        rest = tail;
+    }
+    segments.push(Segment::Text(pending));
+    segments.retain(|x| x != &Segment::Text(String::new()));
+    segments
+}
@@ -0,0 +1,96 @@
+/*! Testing submodule for the transfer module
+ */
+
+use super::*;
+use crate::testing::codepoints;
+
+#[test]
+fn percent_path() {
+    assert_eq!(
+        percent_encode("/a b/café~".as_bytes(), false),
+        "/a%20b/caf%C3%A9~"
+    );
+    assert_eq!(
+        percent_decode("/a%20b/caf%c3%A9+", false),
+        Some("/a b/café+".as_bytes().to_vec())
+    );
+}
+
+#[test]
+fn percent_query() {
+    assert_eq!(percent_encode(b"a b&c=d/e", true), "a+b%26c%3Dd%2Fe");
+    assert_eq!(percent_decode("a+b%26c", true), Some(b"a b&c".to_vec()));
+    assert_eq!(percent_decode("a%2", true), None);
+    assert_eq!(percent_decode("a%zz", true), None);
+}
+
+#[test]
+fn encoded_word() {
+    assert_eq!(
+        encoded_words(&codepoints("café"), &EncodingType::UTF8, "UTF-8", true),
+        Ok(vec!["=?UTF-8?Q?caf=C3=A9?=".to_string()])
+    );
+    assert_eq!(
+        encoded_words(&codepoints("a b?"), &EncodingType::UTF8, "UTF-8", true),
+        Ok(vec!["=?UTF-8?Q?a_b=3F?=".to_string()])
+    );
+    assert_eq!(
+        encoded_words(&codepoints("café"), &EncodingType::UTF8, "UTF-8", false),
+        Ok(vec!["=?UTF-8?B?Y2Fmw6k=?=".to_string()])
+    );
+    assert_eq!(
+        encoded_words(
+            &codepoints("a€"),
+            &EncodingType::Latin1,
+            "ISO-8859-1",
+            false
+        ),
+        Err(vec![1])
+    );
+}
+
+#[test]
+fn encoded_word_split() {
+    let text = codepoints(&"日本語".repeat(10));
+    let words = encoded_words(&text, &EncodingType::UTF8, "UTF-8", false).unwrap();
+    assert!(words.len() > 1);
+    assert!(words.iter().all(|x| x.len() <= WORD_LENGTH));
+    let joined: Vec<u32> = parse_encoded_words(&words.join(" "))
+        .into_iter()
+        .flat_map(|x| match x {
+            Segment::Word { bytes, .. } => codec::decode(&bytes, &EncodingType::UTF8).codepoints,
+            Segment::Text(_) => panic!("Unexpected text between the words"),
+        })
+        .collect();
+    assert_eq!(joined, text);
+}
+
+#[test]
+fn parse_words() {
+    assert_eq!(
+        parse_encoded_words("Re: =?ISO-8859-1?Q?caf=E9?=  =?utf-8?b?w6k=?= ok"),
+        vec![
+            Segment::Text("Re: ".to_string()),
+            Segment::Word {
+                charset: "ISO-8859-1".to_string(),
+                bytes: b"caf\xE9".to_vec()
+            },
+            Segment::Word {
+                charset: "utf-8".to_string(),
+                bytes: "é".as_bytes().to_vec()
+            },
+            Segment::Text(" ok".to_string()),
+        ]
+    );
+    assert_eq!(
+        parse_encoded_words("=?UTF-8?X?abc?="),
+        vec![Segment::Text("=?UTF-8?X?abc?=".to_string())]
+    );
+    assert_eq!(
+        parse_encoded_words("=?UTF-8*en?Q?a_b?="),
+        vec![Segment::Word {
+            charset: "UTF-8".to_string(),
+            bytes: b"a b".to_vec()
+        }]
+    );
+}
@@ -0,0 +1,90 @@
+/*! Transformation of a range of the buffer
+
+Each transformation may change the number of codepoints, so the range is replaced as a whole.
+The codepoints outside of the range are still used as the context of the transformation,
+e.g. to find whether a sigma ends a word.
+The invalid codepoints are kept unchanged.
+ */
+
+mod case;
+mod fold;
+mod styled;
+#[cfg(test)]
+mod testing;
+mod translit;
+mod width;
+
+use crate::cmd::Transform;
+use crate::variation;
+use unicode_normalization::UnicodeNormalization;
+
+/** Transform the codepoints from `start` up to `end`
+
+Return the replacement of that range,
+together with the positions of the codepoints which could not be transformed.
+ */
+pub fn apply(buff: &[u32], start: usize, end: usize, kind: &Transform) -> (Vec<u32>, Vec<usize>) {
+    let mut out = Vec::new();
+    let mut failed = Vec::new();
+    for pos in start..end {
+        let Some(chr) = char::from_u32(buff[pos]) else {
+            if *kind == Transform::AsciiFold {
+                failed.push(pos);
+            }
+            out.push(buff[pos]);
+            continue;
+        };
+        let mapped = match kind {
+            Transform::Upper(locale) => case::upper(buff, pos, chr, locale),
+            Transform::Lower(locale) => case::lower(buff, pos, chr, locale),
+            Transform::Title(locale) => case::title(buff, pos, chr, locale),
+            Transform::StripMarks => fold::strip(chr),
+            Transform::AsciiFold => fold::ascii(chr).unwrap_or_else(|| {
+                failed.push(pos);
+                vec![chr]
+            }),
+            Transform::Fullwidth => width::wide(&buff[start..end], pos - start, chr),
+            Transform::Halfwidth => width::narrow(chr),
+            Transform::Katakana => vec![width::katakana(chr)],
+            Transform::Hiragana => vec![width::hiragana(chr)],
+            Transform::Styled(style) => vec![styled::styled(chr, style).unwrap_or_else(|| {
+                failed.push(pos);
+                chr
+            })],
+            Transform::Plain => vec![styled::plain(chr)],
+            Transform::Latin => match translit::latin(buff, pos, chr) {
+                Some(text) => text.chars().collect(),
+                None => vec![chr],
+            },
+            Transform::EmojiPresentation => {
+                variation::presentation(buff, pos, chr, Some('\u{FE0F}'))
+            }
+            Transform::TextPresentation => {
+                variation::presentation(buff, pos, chr, Some('\u{FE0E}'))
+            }
+            Transform::DefaultPresentation => variation::presentation(buff, pos, chr, None),
+            Transform::StripSelectors if variation::is_selector(chr as u32) => vec![],
+            Transform::StripSelectors => vec![chr],
+        };
+        out.extend(mapped.into_iter().map(|x| x as u32));
+    }
+    (out, failed)
+}
+
+/// Canonical decomposition of the buffer, where the invalid codepoints are kept unchanged
+pub fn decompose(buff: &[u32]) -> Vec<u32> {
+    let mut out = Vec::new();
+    let mut run = String::new();
+    for &code_point in buff {
+        match char::from_u32(code_point) {
+            Some(chr) => run.push(chr),
+            None => {
+                out.extend(run.nfd().map(|x| x as u32));
+                run.clear();
+                out.push(code_point);
+            }
+        }
+    }
+    out.extend(run.nfd().map(|x| x as u32));
+    out
+}
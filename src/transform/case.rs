@@ -0,0 +1,221 @@
+/*! Full case mapping, following SpecialCasing.txt
+
+The unconditional mappings are the ones of the standard library,
+which already expand e.g. `ß` to `SS` in uppercase.
+The conditional mappings depending on the context and on the locale are handled here,
+together with the titlecase mapping which is missing from the standard library.
+ */
+
+mod tables;
+
+use crate::cmd::CaseLocale;
+use crate::codepoint::in_ranges;
+use unicode_normalization::char::canonical_combining_class;
+
+const DOT_ABOVE: char = '\u{0307}';
+/// Canonical combining class of the marks placed above the base
+const ABOVE: u8 = 230;
+
+fn get(buff: &[u32], pos: usize) -> Option<char> {
+    char::from_u32(*buff.get(pos)?)
+}
+
+/// Letter with the Cased property
+fn is_cased(chr: char) -> bool {
+    chr.is_lowercase() || chr.is_uppercase() || is_titlecase(chr)
+}
+
+fn is_titlecase(chr: char) -> bool {
+    matches!(
+        chr,
+        '\u{01C5}'
+            | '\u{01C8}'
+            | '\u{01CB}'
+            | '\u{01F2}'
+            | '\u{1F88}'..='\u{1F8F}'
+            | '\u{1F98}'..='\u{1F9F}'
+            | '\u{1FA8}'..='\u{1FAF}'
+            | '\u{1FBC}'
+            | '\u{1FCC}'
+            | '\u{1FFC}'
+    )
+}
+
+/// Character with the Case_Ignorable property, which is skipped when looking for the cased context
+fn is_case_ignorable(chr: char) -> bool {
+    in_ranges(&tables::CASE_IGNORABLE, chr as u32)
+}
+
+fn is_soft_dotted(chr: char) -> bool {
+    matches!(
+        chr,
+        'i' | 'j'
+            | '\u{012F}'
+            | '\u{0249}'
+            | '\u{0268}'
+            | '\u{029D}'
+            | '\u{02B2}'
+            | '\u{03F3}'
+            | '\u{0456}'
+            | '\u{0458}'
+            | '\u{1D62}'
+            | '\u{1D96}'
+            | '\u{1DA4}'
+            | '\u{1DA8}'
+            | '\u{1E2D}'
+            | '\u{1ECB}'
+            | '\u{2071}'
+            | '\u{2148}'
+            | '\u{2149}'
+            | '\u{2C7C}'
+    )
+}
+
+/// Whether a cased letter preceeds `pos`, skipping the case ignorable characters
+fn cased_before(buff: &[u32], pos: usize) -> bool {
+    (0..pos)
+        .rev()
+        .filter_map(|x| get(buff, x))
+        .find(|&x| !is_case_ignorable(x))
+        .is_some_and(is_cased)
+}
+
+/// Whether a cased letter follows `pos`, skipping the case ignorable characters
+fn cased_after(buff: &[u32], pos: usize) -> bool {
+    (pos + 1..buff.len())
+        .filter_map(|x| get(buff, x))
+        .find(|&x| !is_case_ignorable(x))
+        .is_some_and(is_cased)
+}
+
+/// Position of the nearest base or mark placed above preceeding `pos`
+fn base_before(buff: &[u32], pos: usize) -> Option<usize> {
+    (0..pos).rev().find(|&x| {
+        get(buff, x).is_none_or(|chr| matches!(canonical_combining_class(chr), 0 | ABOVE))
+    })
+}
+
+/// Whether a mark placed above follows `pos`, before the next base
+fn more_above(buff: &[u32], pos: usize) -> bool {
+    (pos + 1..buff.len())
+        .map(|x| get(buff, x).map_or(0, canonical_combining_class))
+        .find(|&x| x == 0 || x == ABOVE)
+        == Some(ABOVE)
+}
+
+/// Whether U+0307 follows `pos`, with only the marks not placed above in between
+fn before_dot(buff: &[u32], pos: usize) -> bool {
+    (pos + 1..buff.len())
+        .filter_map(|x| get(buff, x))
+        .find(|&x| matches!(canonical_combining_class(x), 0 | ABOVE))
+        == Some(DOT_ABOVE)
+}
+
+/// Whether U+0307 at `pos` is placed on a soft dotted letter
+fn after_soft_dotted(buff: &[u32], pos: usize) -> Option<usize> {
+    base_before(buff, pos).filter(|&x| get(buff, x).is_some_and(is_soft_dotted))
+}
+
+/// Whether the cased letter at `pos` starts a word, and is therefore titlecased
+fn starts_word(buff: &[u32], pos: usize) -> bool {
+    get(buff, pos).is_some_and(is_cased) && !cased_before(buff, pos)
+}
+
+pub fn lower(buff: &[u32], pos: usize, chr: char, locale: &CaseLocale) -> Vec<char> {
+    match (locale, chr) {
+        (CaseLocale::Turkic, '\u{0130}') => vec!['i'],
+        (CaseLocale::Turkic, DOT_ABOVE)
+            if base_before(buff, pos).and_then(|x| get(buff, x)) == Some('I') =>
+        {
+            vec![]
+        }
+        (CaseLocale::Turkic, 'I') if !before_dot(buff, pos) => vec!['\u{0131}'],
+        (CaseLocale::Lithuanian, 'I') if more_above(buff, pos) => vec!['i', DOT_ABOVE],
+        (CaseLocale::Lithuanian, 'J') if more_above(buff, pos) => vec!['j', DOT_ABOVE],
+        (CaseLocale::Lithuanian, '\u{012E}') if more_above(buff, pos) => {
+            vec!['\u{012F}', DOT_ABOVE]
+        }
+        (CaseLocale::Lithuanian, '\u{00CC}') => vec!['i', DOT_ABOVE, '\u{0300}'],
+        (CaseLocale::Lithuanian, '\u{00CD}') => vec!['i', DOT_ABOVE, '\u{0301}'],
+        (CaseLocale::Lithuanian, '\u{0128}') => vec!['i', DOT_ABOVE, '\u{0303}'],
+        (_, '\u{03A3}') if cased_before(buff, pos) && !cased_after(buff, pos) => {
+            vec!['\u{03C2}']
+        }
+        _ => chr.to_lowercase().collect(),
+    }
+}
+
+pub fn upper(buff: &[u32], pos: usize, chr: char, locale: &CaseLocale) -> Vec<char> {
+    match (locale, chr) {
+        (CaseLocale::Turkic, 'i') => vec!['\u{0130}'],
+        (CaseLocale::Lithuanian, DOT_ABOVE) if after_soft_dotted(buff, pos).is_some() => vec![],
+        _ => chr.to_uppercase().collect(),
+    }
+}
+
+/** Titlecase the first cased letter of each word, and lowercase the others
+
+A word starts at a cased letter which is not preceeded by another cased letter,
+ignoring the case ignorable characters such as the apostrophe.
+ */
+pub fn title(buff: &[u32], pos: usize, chr: char, locale: &CaseLocale) -> Vec<char> {
+    if !starts_word(buff, pos) {
+        if *locale == CaseLocale::Lithuanian && chr == DOT_ABOVE {
+            if let Some(base) = after_soft_dotted(buff, pos) {
+                if starts_word(buff, base) {
+                    return vec![];
+                }
+            }
+        }
+        return lower(buff, pos, chr, locale);
+    }
+    match (locale, chr) {
+        (CaseLocale::Turkic, 'i') => vec!['\u{0130}'],
+        _ => titlecase(chr).unwrap_or_else(|| chr.to_uppercase().collect()),
+    }
+}
+
+/// Titlecase mapping of the characters where it differs from the uppercase mapping
+fn titlecase(chr: char) -> Option<Vec<char>> {
+    let code = chr as u32;
+    Some(match chr {
+        '\u{01C4}'..='\u{01C6}' => vec!['\u{01C5}'],
+        '\u{01C7}'..='\u{01C9}' => vec!['\u{01C8}'],
+        '\u{01CA}'..='\u{01CC}' => vec!['\u{01CB}'],
+        '\u{01F1}'..='\u{01F3}' => vec!['\u{01F2}'],
+        '\u{00DF}' => vec!['S', 's'],
+        '\u{FB00}' => vec!['F', 'f'],
+        '\u{FB01}' => vec!['F', 'i'],
+        '\u{FB02}' => vec!['F', 'l'],
+        '\u{FB03}' => vec!['F', 'f', 'i'],
+        '\u{FB04}' => vec!['F', 'f', 'l'],
+        '\u{FB05}' | '\u{FB06}' => vec!['S', 't'],
+        '\u{0587}' => vec!['\u{0535}', '\u{0582}'],
+        '\u{FB13}' => vec!['\u{0544}', '\u{0576}'],
+        '\u{FB14}' => vec!['\u{0544}', '\u{0565}'],
+        '\u{FB15}' => vec!['\u{0544}', '\u{056B}'],
+        '\u{FB16}' => vec!['\u{054E}', '\u{0576}'],
+        '\u{FB17}' => vec!['\u{0544}', '\u{056D}'],
+        '\u{0149}' => vec!['\u{02BC}', 'N'],
+        // Greek with ypogegrammeni, titlecased with the prosgegrammeni
+        '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => {
+            vec![char::from_u32(code + 8)?]
+        }
+        '\u{1F88}'..='\u{1F8F}' | '\u{1F98}'..='\u{1F9F}' | '\u{1FA8}'..='\u{1FAF}' => vec![chr],
+        '\u{1FB3}' | '\u{1FBC}' => vec!['\u{1FBC}'],
+        '\u{1FC3}' | '\u{1FCC}' => vec!['\u{1FCC}'],
+        '\u{1FF3}' | '\u{1FFC}' => vec!['\u{1FFC}'],
+        '\u{1FB2}' => vec!['\u{1FBA}', '\u{0345}'],
+        '\u{1FB4}' => vec!['\u{0386}', '\u{0345}'],
+        '\u{1FC2}' => vec!['\u{1FCA}', '\u{0345}'],
+        '\u{1FC4}' => vec!['\u{0389}', '\u{0345}'],
+        '\u{1FF2}' => vec!['\u{1FFA}', '\u{0345}'],
+        '\u{1FF4}' => vec!['\u{038F}', '\u{0345}'],
+        '\u{1FB7}' => vec!['\u{0391}', '\u{0342}', '\u{0345}'],
+        '\u{1FC7}' => vec!['\u{0397}', '\u{0342}', '\u{0345}'],
+        '\u{1FF7}' => vec!['\u{03A9}', '\u{0342}', '\u{0345}'],
+        // Georgian Mkhedruli has no titlecase, only an uppercase in Mtavruli
+        '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}' => vec![chr],
+        _ => return None,
+    })
+}
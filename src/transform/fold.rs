@@ -0,0 +1,84 @@
+/*! Removal of the diacritics and folding to ASCII
+
+The diacritics are removed by decomposing the codepoint, dropping the nonspacing marks,
+and recomposing what is left, so the letters which decompose without any mark, such as
+the Hangul syllables, are kept unchanged.
+The letters without any decomposition, such as `ø` or `ß`, are then transliterated
+with a fixed table.
+ */
+
+mod tables;
+
+use super::decompose;
+use crate::codepoint::in_ranges;
+use unicode_normalization::UnicodeNormalization;
+
+/// Decompose the codepoint, drop the nonspacing marks and recompose the rest
+pub fn strip(chr: char) -> Vec<char> {
+    decompose(&[chr as u32])
+        .into_iter()
+        .filter(|&x| !in_ranges(&tables::NONSPACING_MARKS, x))
+        .filter_map(char::from_u32)
+        .nfc()
+        .collect()
+}
+
+/// Strip the codepoint and transliterate it to ASCII, return None when it could not be folded
+pub fn ascii(chr: char) -> Option<Vec<char>> {
+    let mut out = Vec::new();
+    for x in strip(chr) {
+        if x.is_ascii() {
+            out.push(x);
+        } else {
+            out.extend(transliterate(x)?.chars());
+        }
+    }
+    Some(out)
+}
+
+/// ASCII form of the letters which do not decompose to an ASCII base
+fn transliterate(chr: char) -> Option<&'static str> {
+    Some(match chr {
+        'Æ' | 'Ǣ' | 'Ǽ' => "AE",
+        'æ' | 'ǣ' | 'ǽ' => "ae",
+        'Ø' | 'Ǿ' => "O",
+        'ø' | 'ǿ' => "o",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ł' => "L",
+        'ł' => "l",
+        'Đ' | 'Ð' => "D",
+        'đ' | 'ð' => "d",
+        'Þ' => "Th",
+        'þ' => "th",
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'Ħ' => "H",
+        'ħ' => "h",
+        'ı' => "i",
+        'ĸ' => "q",
+        'Ŀ' => "L",
+        'ŀ' => "l",
+        'Ŋ' => "NG",
+        'ŋ' => "ng",
+        'Ŧ' => "T",
+        'ŧ' => "t",
+        'ſ' => "s",
+        'ƒ' => "f",
+        'Ɨ' => "I",
+        'ɨ' => "i",
+        'Ƶ' => "Z",
+        'ƶ' => "z",
+        'ȷ' => "j",
+        'ﬀ' => "ff",
+        'ﬁ' => "fi",
+        'ﬂ' => "fl",
+        'ﬃ' => "ffi",
+        'ﬄ' => "ffl",
+        'ﬅ' | 'ﬆ' => "st",
+        'Ĳ' => "IJ",
+        'ĳ' => "ij",
+        'ŉ' => "'n",
+        _ => return None,
+    })
+}
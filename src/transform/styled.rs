@@ -0,0 +1,228 @@
+/*! Styled forms of the ASCII letters and digits
+
+Most of the styles are found in the Mathematical Alphanumeric Symbols block,
+where the letters already encoded in the Letterlike Symbols block are left as holes.
+The superscript and subscript forms only exist for part of the letters.
+ */
+
+use crate::cmd::Style;
+
+/// Every style, used to find back the plain form of a styled character
+const STYLES: [Style; 16] = [
+    Style::Bold,
+    Style::Italic,
+    Style::BoldItalic,
+    Style::Script,
+    Style::BoldScript,
+    Style::Fraktur,
+    Style::BoldFraktur,
+    Style::DoubleStruck,
+    Style::Sans,
+    Style::SansBold,
+    Style::SansItalic,
+    Style::SansBoldItalic,
+    Style::Monospace,
+    Style::Circled,
+    Style::Superscript,
+    Style::Subscript,
+];
+
+/// Characters which have a styled form, beside the letters and the digits
+const SIGNS: [char; 5] = ['+', '-', '=', '(', ')'];
+
+/// First codepoint of the uppercase letters, and of the digits when they exist
+fn math_start(style: &Style) -> Option<(u32, Option<u32>)> {
+    Some(match style {
+        Style::Bold => (0x1D400, Some(0x1D7CE)),
+        Style::Italic => (0x1D434, None),
+        Style::BoldItalic => (0x1D468, None),
+        Style::Script => (0x1D49C, None),
+        Style::BoldScript => (0x1D4D0, None),
+        Style::Fraktur => (0x1D504, None),
+        Style::DoubleStruck => (0x1D538, Some(0x1D7D8)),
+        Style::BoldFraktur => (0x1D56C, None),
+        Style::Sans => (0x1D5A0, Some(0x1D7E2)),
+        Style::SansBold => (0x1D5D4, Some(0x1D7EC)),
+        Style::SansItalic => (0x1D608, None),
+        Style::SansBoldItalic => (0x1D63C, None),
+        Style::Monospace => (0x1D670, Some(0x1D7F6)),
+        Style::Circled | Style::Superscript | Style::Subscript => return None,
+    })
+}
+
+/// Letterlike symbol in place of the reserved codepoint of the mathematical block
+fn hole(code: u32) -> Option<char> {
+    Some(match code {
+        0x1D455 => 'ℎ',
+        0x1D49D => 'ℬ',
+        0x1D4A0 => 'ℰ',
+        0x1D4A1 => 'ℱ',
+        0x1D4A3 => 'ℋ',
+        0x1D4A4 => 'ℐ',
+        0x1D4A7 => 'ℒ',
+        0x1D4A8 => 'ℳ',
+        0x1D4AD => 'ℛ',
+        0x1D4BA => 'ℯ',
+        0x1D4BC => 'ℊ',
+        0x1D4C4 => 'ℴ',
+        0x1D506 => 'ℭ',
+        0x1D50B => 'ℌ',
+        0x1D50C => 'ℑ',
+        0x1D515 => 'ℜ',
+        0x1D51D => 'ℨ',
+        0x1D53A => 'ℂ',
+        0x1D53F => 'ℍ',
+        0x1D545 => 'ℕ',
+        0x1D547 => 'ℙ',
+        0x1D548 => 'ℚ',
+        0x1D549 => 'ℝ',
+        0x1D551 => 'ℤ',
+        _ => return None,
+    })
+}
+
+fn math(chr: char, style: &Style) -> Option<char> {
+    let (letters, digits) = math_start(style)?;
+    let code = match chr {
+        'A'..='Z' => letters + (chr as u32 - 'A' as u32),
+        'a'..='z' => letters + 26 + (chr as u32 - 'a' as u32),
+        '0'..='9' => digits? + (chr as u32 - '0' as u32),
+        _ => return None,
+    };
+    hole(code).or_else(|| char::from_u32(code))
+}
+
+fn circled(chr: char) -> Option<char> {
+    let code = match chr {
+        'A'..='Z' => 0x24B6 + (chr as u32 - 'A' as u32),
+        'a'..='z' => 0x24D0 + (chr as u32 - 'a' as u32),
+        '0' => 0x24EA,
+        '1'..='9' => 0x2460 + (chr as u32 - '1' as u32),
+        _ => return None,
+    };
+    char::from_u32(code)
+}
+
+fn superscript(chr: char) -> Option<char> {
+    Some(match chr {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4'..='9' => char::from_u32(0x2074 + (chr as u32 - '4' as u32))?,
+        '+' => '⁺',
+        '-' => '⁻',
+        '=' => '⁼',
+        '(' => '⁽',
+        ')' => '⁾',
+        'a' => 'ᵃ',
+        'b' => 'ᵇ',
+        'c' => 'ᶜ',
+        'd' => 'ᵈ',
+        'e' => 'ᵉ',
+        'f' => 'ᶠ',
+        'g' => 'ᵍ',
+        'h' => 'ʰ',
+        'i' => 'ⁱ',
+        'j' => 'ʲ',
+        'k' => 'ᵏ',
+        'l' => 'ˡ',
+        'm' => 'ᵐ',
+        'n' => 'ⁿ',
+        'o' => 'ᵒ',
+        'p' => 'ᵖ',
+        'r' => 'ʳ',
+        's' => 'ˢ',
+        't' => 'ᵗ',
+        'u' => 'ᵘ',
+        'v' => 'ᵛ',
+        'w' => 'ʷ',
+        'x' => 'ˣ',
+        'y' => 'ʸ',
+        'z' => 'ᶻ',
+        'A' => 'ᴬ',
+        'B' => 'ᴮ',
+        'D' => 'ᴰ',
+        'E' => 'ᴱ',
+        'G' => 'ᴳ',
+        'H' => 'ᴴ',
+        'I' => 'ᴵ',
+        'J' => 'ᴶ',
+        'K' => 'ᴷ',
+        'L' => 'ᴸ',
+        'M' => 'ᴹ',
+        'N' => 'ᴺ',
+        'O' => 'ᴼ',
+        'P' => 'ᴾ',
+        'R' => 'ᴿ',
+        'T' => 'ᵀ',
+        'U' => 'ᵁ',
+        'V' => 'ⱽ',
+        'W' => 'ᵂ',
+        _ => return None,
+    })
+}
+
+fn subscript(chr: char) -> Option<char> {
+    Some(match chr {
+        '0'..='9' => char::from_u32(0x2080 + (chr as u32 - '0' as u32))?,
+        '+' => '₊',
+        '-' => '₋',
+        '=' => '₌',
+        '(' => '₍',
+        ')' => '₎',
+        'a' => 'ₐ',
+        'e' => 'ₑ',
+        'h' => 'ₕ',
+        'i' => 'ᵢ',
+        'j' => 'ⱼ',
+        'k' => 'ₖ',
+        'l' => 'ₗ',
+        'm' => 'ₘ',
+        'n' => 'ₙ',
+        'o' => 'ₒ',
+        'p' => 'ₚ',
+        'r' => 'ᵣ',
+        's' => 'ₛ',
+        't' => 'ₜ',
+        'u' => 'ᵤ',
+        'v' => 'ᵥ',
+        'x' => 'ₓ',
+        _ => return None,
+    })
+}
+
+fn form(chr: char, style: &Style) -> Option<char> {
+    match style {
+        Style::Circled => circled(chr),
+        Style::Superscript => superscript(chr),
+        Style::Subscript => subscript(chr),
+        _ => math(chr, style),
+    }
+}
+
+/** Styled form of the character
+
+The characters other than the ASCII letters and digits are kept unchanged when they have no form.
+Return None when the letter or the digit has no form in the style.
+ */
+pub fn styled(chr: char, style: &Style) -> Option<char> {
+    form(chr, style).or_else(|| (!chr.is_ascii_alphanumeric()).then_some(chr))
+}
+
+/// Plain ASCII form of the styled character, or the character itself when it is not styled
+pub fn plain(chr: char) -> char {
+    if chr.is_ascii() {
+        return chr;
+    }
+    STYLES
+        .iter()
+        .find_map(|style| {
+            ('0'..='9')
+                .chain('A'..='Z')
+                .chain('a'..='z')
+                .chain(SIGNS)
+                .find(|&base| form(base, style) == Some(chr))
+        })
+        .unwrap_or(chr)
+}
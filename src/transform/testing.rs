@@ -0,0 +1,349 @@
+/*! Testing submodule for the transform module
+ */
+
+use super::*;
+use crate::cmd::{CaseLocale, Style};
+use crate::testing::codepoints;
+
+fn whole(text: &str, kind: Transform) -> Vec<u32> {
+    let buff = codepoints(text);
+    apply(&buff, 0, buff.len(), &kind).0
+}
+
+#[test]
+fn upper_expanding() {
+    assert_eq!(
+        whole("straße ŉ ﬁ", Transform::Upper(CaseLocale::Root)),
+        codepoints("STRASSE ʼN FI")
+    );
+    assert_eq!(
+        whole("ΐ", Transform::Upper(CaseLocale::Root)),
+        codepoints("\u{0399}\u{0308}\u{0301}")
+    );
+}
+
+#[test]
+fn lower_final_sigma() {
+    assert_eq!(
+        whole("ΟΔΟΣ ΣΑΣ.", Transform::Lower(CaseLocale::Root)),
+        codepoints("οδος σας.")
+    );
+    // A lone sigma is not final
+    assert_eq!(
+        whole("Σ", Transform::Lower(CaseLocale::Root)),
+        codepoints("σ")
+    );
+    // The fullwidth apostrophe and the iteration mark are case ignorable, so the sigma is not final
+    assert_eq!(
+        whole(
+            "ΑΣ\u{FF07}Α ΑΣ\u{3005}Α",
+            Transform::Lower(CaseLocale::Root)
+        ),
+        codepoints("ασ\u{FF07}α ασ\u{3005}α")
+    );
+    assert_eq!(
+        whole("ΑΣ\u{FF07}", Transform::Lower(CaseLocale::Root)),
+        codepoints("ας\u{FF07}")
+    );
+    assert_eq!(
+        whole("İ", Transform::Lower(CaseLocale::Root)),
+        codepoints("i\u{0307}")
+    );
+}
+
+#[test]
+fn title_words() {
+    assert_eq!(
+        whole("hello wORLD don't", Transform::Title(CaseLocale::Root)),
+        codepoints("Hello World Don't")
+    );
+    assert_eq!(
+        whole("ǆemal ßa ﬂour", Transform::Title(CaseLocale::Root)),
+        codepoints("ǅemal Ssa Flour")
+    );
+    assert_eq!(
+        whole("ᾳ ა", Transform::Title(CaseLocale::Root)),
+        codepoints("ᾼ ა")
+    );
+}
+
+#[test]
+fn turkic() {
+    assert_eq!(
+        whole("istanbul ıi", Transform::Upper(CaseLocale::Turkic)),
+        codepoints("İSTANBUL Iİ")
+    );
+    assert_eq!(
+        whole(
+            "İSTANBUL DIŞ I\u{0307}",
+            Transform::Lower(CaseLocale::Turkic)
+        ),
+        codepoints("istanbul dış i")
+    );
+    assert_eq!(
+        whole("iyi", Transform::Title(CaseLocale::Turkic)),
+        codepoints("İyi")
+    );
+}
+
+#[test]
+fn lithuanian() {
+    assert_eq!(
+        whole(
+            "I\u{0300} Ì Į\u{0301} I",
+            Transform::Lower(CaseLocale::Lithuanian)
+        ),
+        codepoints("i\u{0307}\u{0300} i\u{0307}\u{0300} į\u{0307}\u{0301} i")
+    );
+    assert_eq!(
+        whole(
+            "i\u{0307}\u{0300} į\u{0307}",
+            Transform::Upper(CaseLocale::Lithuanian)
+        ),
+        codepoints("I\u{0300} Į")
+    );
+    assert_eq!(
+        whole(
+            "i\u{0307}\u{0300}i\u{0307}",
+            Transform::Title(CaseLocale::Lithuanian)
+        ),
+        codepoints("I\u{0300}i\u{0307}")
+    );
+}
+
+#[test]
+fn range_context() {
+    let buff = codepoints("ΑΣ ΒΣ");
+    // The sigma is final within the whole buffer, even if the range stops there
+    assert_eq!(
+        apply(&buff, 1, 2, &Transform::Lower(CaseLocale::Root)),
+        (codepoints("ς"), vec![])
+    );
+    assert_eq!(
+        apply(
+            &[0x61, 0xD800, 0x62],
+            0,
+            3,
+            &Transform::Upper(CaseLocale::Root)
+        ),
+        (vec![0x41, 0xD800, 0x42], vec![])
+    );
+}
+
+#[test]
+fn strip_marks() {
+    assert_eq!(
+        whole("Crème Brûlée Ǆ ø", Transform::StripMarks),
+        codepoints("Creme Brulee Ǆ ø")
+    );
+    // The marks already decomposed are also removed
+    assert_eq!(
+        whole("a\u{0301}\u{0323}", Transform::StripMarks),
+        codepoints("a")
+    );
+    // The Hangul syllables are recomposed after the decomposition
+    assert_eq!(
+        whole("한글 café", Transform::StripMarks),
+        codepoints("한글 cafe")
+    );
+    // The nonspacing marks of the combining class 0 are removed
+    assert_eq!(
+        whole("\u{0E01}\u{0E31}\u{0E19}", Transform::StripMarks),
+        codepoints("\u{0E01}\u{0E19}")
+    );
+}
+
+#[test]
+fn ascii_fold() {
+    let buff = codepoints("Łódź Æsir Søren Straße ﬁn");
+    assert_eq!(
+        apply(&buff, 0, buff.len(), &Transform::AsciiFold),
+        (codepoints("Lodz AEsir Soren Strasse fin"), vec![])
+    );
+    let buff = codepoints("Ωa€");
+    assert_eq!(
+        apply(&buff, 0, buff.len(), &Transform::AsciiFold),
+        (buff.clone(), vec![0, 2])
+    );
+    assert_eq!(
+        apply(&[0xD800, 0xE9], 0, 2, &Transform::AsciiFold),
+        (vec![0xD800, 0x65], vec![0])
+    );
+}
+
+#[test]
+fn decompose_invalid() {
+    assert_eq!(
+        decompose(&[0xE9, 0xD800, 0x1E0B, 0x0323]),
+        vec![0x65, 0x301, 0xD800, 0x64, 0x323, 0x307]
+    );
+}
+
+#[test]
+fn fullwidth() {
+    assert_eq!(
+        whole("Ab 1! ¥", Transform::Fullwidth),
+        codepoints("Ａｂ　１！　￥")
+    );
+    assert_eq!(
+        whole("ｶﾞｷﾞﾊﾟｱﾞｰｿﾌﾄ｡ﾞ", Transform::Fullwidth),
+        codepoints("ガギパア゛ーソフト。゛")
+    );
+    assert_eq!(whole("ￜ￩", Transform::Fullwidth), codepoints("ￜ←"));
+    // The sound mark is only joined when both the katakana and the mark are in the range
+    let buff = codepoints("ｶﾞ");
+    assert_eq!(
+        apply(&buff, 0, 1, &Transform::Fullwidth).0,
+        codepoints("カ")
+    );
+    assert_eq!(
+        apply(&buff, 1, 2, &Transform::Fullwidth).0,
+        codepoints("゛")
+    );
+}
+
+#[test]
+fn halfwidth() {
+    assert_eq!(
+        whole("Ａｂ　１！　￥", Transform::Halfwidth),
+        codepoints("Ab 1! ¥")
+    );
+    assert_eq!(
+        whole("ガパヴ「ソフト」ヶ", Transform::Halfwidth),
+        codepoints("ｶﾞﾊﾟｳﾞ｢ｿﾌﾄ｣ヶ")
+    );
+    let text = codepoints("ｶﾞｷﾞﾊﾟｱﾞｰｿﾌﾄ");
+    let wide = apply(&text, 0, text.len(), &Transform::Fullwidth).0;
+    assert_eq!(apply(&wide, 0, wide.len(), &Transform::Halfwidth).0, text);
+}
+
+#[test]
+fn kana() {
+    assert_eq!(
+        whole("ひらがなゝ カタカナ", Transform::Katakana),
+        codepoints("ヒラガナヽ カタカナ")
+    );
+    assert_eq!(
+        whole("カタカナヾヷ ひらがな", Transform::Hiragana),
+        codepoints("かたかなゞヷ ひらがな")
+    );
+}
+
+#[test]
+fn styled_letters() {
+    assert_eq!(
+        whole("bold 42", Transform::Styled(Style::Bold)),
+        codepoints("𝐛𝐨𝐥𝐝 𝟒𝟐")
+    );
+    assert_eq!(
+        whole("italic h", Transform::Styled(Style::Italic)),
+        codepoints("𝑖𝑡𝑎𝑙𝑖𝑐 ℎ")
+    );
+    assert_eq!(
+        whole("CHRZ", Transform::Styled(Style::DoubleStruck)),
+        codepoints("ℂℍℝℤ")
+    );
+    assert_eq!(
+        whole("Hi", Transform::Styled(Style::Fraktur)),
+        codepoints("ℌ𝔦")
+    );
+    assert_eq!(
+        whole("A0 9", Transform::Styled(Style::Circled)),
+        codepoints("Ⓐ⓪ ⑨")
+    );
+    assert_eq!(
+        whole("x2+1", Transform::Styled(Style::Superscript)),
+        codepoints("ˣ²⁺¹")
+    );
+    assert_eq!(
+        whole("H2O", Transform::Styled(Style::Subscript)),
+        vec![0x48, 0x2082, 0x4F]
+    );
+}
+
+#[test]
+fn styled_missing() {
+    let buff = codepoints("qQ1");
+    assert_eq!(
+        apply(&buff, 0, 3, &Transform::Styled(Style::Superscript)),
+        (codepoints("qQ¹"), vec![0, 1])
+    );
+    assert_eq!(
+        apply(&buff, 0, 3, &Transform::Styled(Style::Italic)),
+        (codepoints("𝑞𝑄1"), vec![2])
+    );
+}
+
+#[test]
+fn plain_letters() {
+    assert_eq!(
+        whole("𝐛𝐨𝐥𝐝 𝘪𝘵𝘢𝘭𝘪𝘤 ℌ𝔦 ⓐ① ˣ²⁺ H₂O é", Transform::Plain),
+        codepoints("bold italic Hi a1 x2+ H2O é")
+    );
+}
+
+#[test]
+fn latin_cyrillic() {
+    assert_eq!(
+        whole("Москва, Щука, Їжак, Љубљана", Transform::Latin),
+        codepoints("Moskva, Ŝuka, Ïžak, L\u{0302}ubl\u{0302}ana")
+    );
+}
+
+#[test]
+fn latin_greek() {
+    assert_eq!(
+        whole(
+            "Αθήνα ΘΕΣΣΑΛΟΝΙΚΗ Ευρώπη άγγελος μούσα\u{037E}",
+            Transform::Latin
+        ),
+        codepoints("Athi\u{0301}na ThESSALONIKI Evro\u{0301}pi a\u{0301}ngelos mou\u{0301}sa?")
+    );
+}
+
+#[test]
+fn latin_arabic_hebrew() {
+    assert_eq!(
+        whole("كتاب مُحَمَّد ١٢", Transform::Latin),
+        codepoints("ktāb muḥammad 12")
+    );
+    assert_eq!(
+        whole("שָׁלוֹם בּוּשׁ כֶּלֶב", Transform::Latin),
+        codepoints("shalom bush kelev")
+    );
+}
+
+#[test]
+fn latin_hangul() {
+    assert_eq!(
+        whole("한국어 서울 닭 ㄱ", Transform::Latin),
+        codepoints("hangugeo seoul dak ㄱ")
+    );
+}
+
+#[test]
+fn presentation_selectors() {
+    let buff = vec![0x2764, 0x263A, 0xFE0E, 0x31, 0x32, 0x20E3, 0x261D, 0x1F3FD];
+    assert_eq!(
+        apply(&buff, 0, buff.len(), &Transform::EmojiPresentation).0,
+        vec![0x2764, 0xFE0F, 0x263A, 0xFE0F, 0x31, 0x32, 0xFE0F, 0x20E3, 0x261D, 0x1F3FD]
+    );
+    assert_eq!(
+        apply(&buff, 0, buff.len(), &Transform::TextPresentation).0,
+        vec![0x2764, 0xFE0E, 0x263A, 0xFE0E, 0x31, 0x32, 0xFE0E, 0x20E3, 0x261D, 0x1F3FD]
+    );
+    assert_eq!(
+        apply(&buff, 0, buff.len(), &Transform::DefaultPresentation).0,
+        vec![0x2764, 0x263A, 0x31, 0x32, 0x20E3, 0x261D, 0x1F3FD]
+    );
+    assert_eq!(
+        apply(
+            &[0x6F22, 0xFE01, 0x2764, 0xFE0F, 0x41],
+            0,
+            5,
+            &Transform::StripSelectors
+        )
+        .0,
+        vec![0x6F22, 0x2764, 0x41]
+    );
+}
@@ -0,0 +1,337 @@
+/*! Transliteration of the non-Latin scripts to Latin
+
+The tables follow ISO 9 for Cyrillic, ISO 843 for Greek, ALA-LC for Arabic and Hebrew,
+and the Revised Romanization for Hangul, where the final consonant followed by a vowel
+is carried over to the next syllable.
+Only the letter-by-letter rules are applied, together with the few contextual rules
+which depend on the marks or the neighbouring letters, e.g. `ου` to `ou` in Greek.
+ */
+
+use crate::hangul;
+use unicode_normalization::char::decompose_canonical;
+
+fn get(buff: &[u32], pos: usize) -> Option<char> {
+    char::from_u32(*buff.get(pos)?)
+}
+
+/// Capitalize the first letter of the transliteration of an uppercase letter
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Transliterate the letter, with the case of the original letter
+fn cased(chr: char, table: fn(char) -> Option<&'static str>) -> Option<String> {
+    if chr.is_uppercase() {
+        let lower = chr.to_lowercase().next()?;
+        Some(capitalize(table(lower)?))
+    } else {
+        Some(table(chr)?.to_string())
+    }
+}
+
+fn cyrillic(chr: char) -> Option<&'static str> {
+    Some(match chr {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'д' => "d",
+        'е' => "e",
+        'ё' => "ë",
+        'ж' => "ž",
+        'з' => "z",
+        'и' => "i",
+        'й' => "j",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "h",
+        'ц' => "c",
+        'ч' => "č",
+        'ш' => "š",
+        'щ' => "ŝ",
+        'ъ' => "ʺ",
+        'ы' => "y",
+        'ь' => "ʹ",
+        'э' => "è",
+        'ю' => "û",
+        'я' => "â",
+        'ґ' => "g\u{0300}",
+        'є' => "ê",
+        'і' => "ì",
+        'ї' => "ï",
+        'ў' => "ŭ",
+        'ђ' => "đ",
+        'ј' => "ǰ",
+        'љ' => "l\u{0302}",
+        'њ' => "n\u{0302}",
+        'ћ' => "ć",
+        'џ' => "d\u{0302}",
+        'ѓ' => "ǵ",
+        'ѕ' => "ẑ",
+        'ќ' => "ḱ",
+        _ => return None,
+    })
+}
+
+fn greek(chr: char) -> Option<&'static str> {
+    Some(match chr {
+        'α' => "a",
+        'β' => "v",
+        'γ' => "g",
+        'δ' => "d",
+        'ε' => "e",
+        'ζ' => "z",
+        'η' => "i",
+        'θ' => "th",
+        'ι' => "i",
+        'κ' => "k",
+        'λ' => "l",
+        'μ' => "m",
+        'ν' => "n",
+        'ξ' => "x",
+        'ο' => "o",
+        'π' => "p",
+        'ρ' => "r",
+        'σ' | 'ς' => "s",
+        'τ' => "t",
+        'υ' => "y",
+        'φ' => "f",
+        'χ' => "ch",
+        'ψ' => "ps",
+        'ω' => "o",
+        ';' => "?",
+        '·' => ";",
+        _ => return None,
+    })
+}
+
+/// Base letter of the Greek codepoint, without the marks
+fn greek_base(chr: Option<char>) -> Option<char> {
+    let mut base = None;
+    decompose_canonical(chr?, |x| {
+        base.get_or_insert(x);
+    });
+    base.map(|x| x.to_lowercase().next().unwrap_or(x))
+}
+
+/// Transliterate the Greek letter, keeping the acute accent and the diaeresis
+fn greek_letter(buff: &[u32], pos: usize, chr: char) -> Option<String> {
+    let mut parts = Vec::new();
+    decompose_canonical(chr, |x| parts.push(x));
+    let base = parts[0];
+    let lower = base.to_lowercase().next()?;
+    let before = pos.checked_sub(1).and_then(|x| greek_base(get(buff, x)));
+    let after = greek_base(get(buff, pos + 1));
+    let latin = match (lower, before, after) {
+        ('υ', Some('ο'), _) => "u",
+        ('υ', Some('α' | 'ε' | 'η'), _) => "v",
+        ('γ', _, Some('γ' | 'κ' | 'ξ' | 'χ')) => "n",
+        _ => greek(lower)?,
+    };
+    let mut out = if base.is_uppercase() {
+        capitalize(latin)
+    } else {
+        latin.to_string()
+    };
+    out.extend(
+        parts[1..]
+            .iter()
+            .filter(|&&x| x == '\u{0301}' || x == '\u{0308}'),
+    );
+    Some(out)
+}
+
+fn arabic(chr: char) -> Option<&'static str> {
+    Some(match chr {
+        'ا' | 'آ' => "ā",
+        'ب' => "b",
+        'ت' => "t",
+        'ث' => "th",
+        'ج' => "j",
+        'ح' => "ḥ",
+        'خ' => "kh",
+        'د' => "d",
+        'ذ' => "dh",
+        'ر' => "r",
+        'ز' => "z",
+        'س' => "s",
+        'ش' => "sh",
+        'ص' => "ṣ",
+        'ض' => "ḍ",
+        'ط' => "ṭ",
+        'ظ' => "ẓ",
+        'ع' => "ʻ",
+        'غ' => "gh",
+        'ف' => "f",
+        'ق' => "q",
+        'ك' | 'ک' => "k",
+        'ل' => "l",
+        'م' => "m",
+        'ن' => "n",
+        'ه' | 'ة' => "h",
+        'و' => "w",
+        'ي' | 'ی' => "y",
+        'ى' => "á",
+        'ء' | 'أ' | 'إ' | 'ؤ' | 'ئ' => "ʼ",
+        'پ' => "p",
+        'چ' => "ch",
+        'ژ' => "zh",
+        'گ' => "g",
+        '\u{064B}' => "an",
+        '\u{064C}' => "un",
+        '\u{064D}' => "in",
+        '\u{064E}' => "a",
+        '\u{064F}' => "u",
+        '\u{0650}' => "i",
+        '\u{0652}' => "",
+        '\u{0640}' => "",
+        '،' => ",",
+        '؛' => ";",
+        '؟' => "?",
+        '٠' => "0",
+        '١' => "1",
+        '٢' => "2",
+        '٣' => "3",
+        '٤' => "4",
+        '٥' => "5",
+        '٦' => "6",
+        '٧' => "7",
+        '٨' => "8",
+        '٩' => "9",
+        _ => return None,
+    })
+}
+
+fn arabic_letter(buff: &[u32], pos: usize, chr: char) -> Option<String> {
+    let starts_word = pos == 0 || get(buff, pos - 1).is_none_or(|x| !x.is_alphabetic());
+    // The shadda doubles the consonant it is placed on
+    let shadda = (pos + 1..buff.len())
+        .map_while(|x| get(buff, x).filter(|c| matches!(c, '\u{064B}'..='\u{0652}')))
+        .any(|x| x == '\u{0651}');
+    match chr {
+        'ا' if starts_word => Some("a".to_string()),
+        '\u{0651}' => Some(String::new()),
+        '\u{064B}'..='\u{0652}' => Some(arabic(chr)?.to_string()),
+        _ if shadda => Some(arabic(chr)?.repeat(2)),
+        _ => Some(arabic(chr)?.to_string()),
+    }
+}
+
+fn is_hebrew_point(chr: char) -> bool {
+    matches!(chr, '\u{0591}'..='\u{05C7}')
+        && !matches!(chr, '\u{05BE}' | '\u{05C0}' | '\u{05C3}' | '\u{05C6}')
+}
+
+fn hebrew_letter(buff: &[u32], pos: usize, chr: char) -> Option<String> {
+    let points: Vec<char> = (pos + 1..buff.len())
+        .map_while(|x| get(buff, x).filter(|&c| is_hebrew_point(c)))
+        .collect();
+    let dagesh = points.contains(&'\u{05BC}');
+    let base_before = || {
+        (0..pos)
+            .rev()
+            .filter_map(|x| get(buff, x))
+            .find(|&x| !is_hebrew_point(x))
+    };
+    Some(
+        match chr {
+            'א' => "ʼ",
+            'ב' if dagesh => "b",
+            'ב' => "v",
+            'ג' => "g",
+            'ד' => "d",
+            'ה' => "h",
+            'ו' if points.contains(&'\u{05B9}') || points.contains(&'\u{05BA}') => "o",
+            'ו' if dagesh => "u",
+            'ו' => "v",
+            'ז' => "z",
+            'ח' => "ḥ",
+            'ט' => "ṭ",
+            'י' => "y",
+            'כ' | 'ך' if dagesh => "k",
+            'כ' | 'ך' => "kh",
+            'ל' => "l",
+            'מ' | 'ם' => "m",
+            'נ' | 'ן' => "n",
+            'ס' => "s",
+            'ע' => "ʻ",
+            'פ' | 'ף' if dagesh => "p",
+            'פ' | 'ף' => "f",
+            'צ' | 'ץ' => "ts",
+            'ק' => "k",
+            'ר' => "r",
+            'ש' if points.contains(&'\u{05C2}') => "s",
+            'ש' => "sh",
+            'ת' => "t",
+            // The vowels written with the vav are already transliterated with the vav
+            '\u{05B9}' | '\u{05BA}' | '\u{05BC}' if base_before() == Some('ו') => "",
+            '\u{05B1}' | '\u{05B5}' | '\u{05B6}' => "e",
+            '\u{05B2}' | '\u{05B7}' | '\u{05B8}' | '\u{05C7}' => "a",
+            '\u{05B3}' | '\u{05B9}' | '\u{05BA}' => "o",
+            '\u{05B4}' => "i",
+            '\u{05BB}' => "u",
+            '\u{05B0}' | '\u{05BC}' | '\u{05BD}' | '\u{05BF}' | '\u{05C1}' | '\u{05C2}' => "",
+            '\u{05BE}' => "-",
+            '\u{05F3}' => "'",
+            '\u{05F4}' => "\"",
+            _ => return None,
+        }
+        .to_string(),
+    )
+}
+
+const HANGUL_TAILS: [&str; 28] = [
+    "", "k", "k", "k", "n", "n", "n", "t", "l", "k", "m", "l", "l", "l", "p", "l", "m", "p", "p",
+    "t", "t", "ng", "t", "t", "k", "t", "p", "t",
+];
+
+/// Final consonants carried over to the next syllable starting with a vowel
+const HANGUL_LIAISONS: [&str; 28] = [
+    "", "g", "kk", "gs", "n", "nj", "n", "d", "r", "lg", "lm", "lb", "ls", "lt", "lp", "r", "m",
+    "b", "bs", "s", "ss", "ng", "j", "ch", "k", "t", "p", "",
+];
+/// Index of the silent initial consonant
+const HANGUL_SILENT: usize = 11;
+
+fn hangul(buff: &[u32], pos: usize, chr: char) -> Option<String> {
+    let jamo = hangul::decompose(chr as u32)?;
+    let liaison = buff
+        .get(pos + 1)
+        .and_then(|&x| hangul::decompose(x))
+        .is_some_and(|x| x.lead == HANGUL_SILENT);
+    Some(format!(
+        "{}{}{}",
+        hangul::LEADS[jamo.lead],
+        hangul::VOWELS[jamo.vowel],
+        if liaison {
+            HANGUL_LIAISONS[jamo.tail]
+        } else {
+            HANGUL_TAILS[jamo.tail]
+        }
+    ))
+}
+
+/// Transliterate the codepoint at `pos`, return None when its script is not supported
+pub fn latin(buff: &[u32], pos: usize, chr: char) -> Option<String> {
+    match chr {
+        '\u{0400}'..='\u{04FF}' => cased(chr, cyrillic),
+        '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' => greek_letter(buff, pos, chr),
+        '\u{0600}'..='\u{06FF}' => arabic_letter(buff, pos, chr),
+        '\u{0590}'..='\u{05FF}' => hebrew_letter(buff, pos, chr),
+        '\u{AC00}'..='\u{D7A3}' => hangul(buff, pos, chr),
+        _ => None,
+    }
+}
@@ -0,0 +1,154 @@
+/*! Conversion between the fullwidth and the halfwidth forms, and between the kana
+
+The halfwidth katakana write the voiced sound mark as a separate codepoint,
+so a fullwidth katakana with the mark is split in two when converted to the halfwidth form,
+and the two are joined back when converted to the fullwidth form.
+ */
+
+use unicode_normalization::char::{compose, decompose_canonical};
+
+/// Offset between the ASCII and the fullwidth ASCII
+const WIDE_OFFSET: u32 = 0xFEE0;
+const IDEOGRAPHIC_SPACE: char = '\u{3000}';
+/// Combining voiced and semi-voiced sound marks
+const VOICED: [char; 2] = ['\u{3099}', '\u{309A}'];
+/// Halfwidth voiced and semi-voiced sound marks
+const HALF_VOICED: [char; 2] = ['\u{FF9E}', '\u{FF9F}'];
+
+/// Fullwidth form of the halfwidth katakana and punctuation, from U+FF61 up to U+FF9F
+const HALF_KATAKANA: [char; 63] = [
+    '。', '「', '」', '、', '・', 'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー',
+    'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ',
+    'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ',
+    'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ン', '゛', '゜',
+];
+
+/// Pairs of the fullwidth symbols and their halfwidth form
+const SYMBOLS: [(char, char); 9] = [
+    ('\u{FFE0}', '\u{00A2}'),
+    ('\u{FFE1}', '\u{00A3}'),
+    ('\u{FFE2}', '\u{00AC}'),
+    ('\u{FFE3}', '\u{00AF}'),
+    ('\u{FFE4}', '\u{00A6}'),
+    ('\u{FFE5}', '\u{00A5}'),
+    ('\u{FFE6}', '\u{20A9}'),
+    ('\u{FF5F}', '\u{2985}'),
+    ('\u{FF60}', '\u{2986}'),
+];
+
+/// Pairs of the halfwidth forms and their regular form
+const HALF_FORMS: [(char, char); 7] = [
+    ('\u{FFE8}', '\u{2502}'),
+    ('\u{FFE9}', '\u{2190}'),
+    ('\u{FFEA}', '\u{2191}'),
+    ('\u{FFEB}', '\u{2192}'),
+    ('\u{FFEC}', '\u{2193}'),
+    ('\u{FFED}', '\u{25A0}'),
+    ('\u{FFEE}', '\u{25CB}'),
+];
+
+fn get(buff: &[u32], pos: usize) -> Option<char> {
+    char::from_u32(*buff.get(pos)?)
+}
+
+fn half_katakana(chr: char) -> Option<char> {
+    let idx = (chr as u32).checked_sub(0xFF61)?;
+    HALF_KATAKANA.get(idx as usize).copied()
+}
+
+/// Join the halfwidth katakana with the following halfwidth sound mark, when it exists
+fn join_voiced(base: char, mark: Option<char>) -> Option<char> {
+    let idx = HALF_VOICED.iter().position(|&x| Some(x) == mark)?;
+    compose(base, VOICED[idx])
+}
+
+/** Convert to the fullwidth form
+
+The `buff` only holds the range being converted, so the sound mark is joined
+to the katakana only when both of them are within the range.
+ */
+pub fn wide(buff: &[u32], pos: usize, chr: char) -> Vec<char> {
+    if HALF_VOICED.contains(&chr) {
+        let joined = pos
+            .checked_sub(1)
+            .and_then(|x| get(buff, x))
+            .and_then(half_katakana)
+            .and_then(|base| join_voiced(base, Some(chr)));
+        if joined.is_some() {
+            return vec![];
+        }
+    }
+    if let Some(base) = half_katakana(chr) {
+        return vec![join_voiced(base, get(buff, pos + 1)).unwrap_or(base)];
+    }
+    let wide = match chr {
+        ' ' => IDEOGRAPHIC_SPACE,
+        '!'..='~' => char::from_u32(chr as u32 + WIDE_OFFSET).unwrap_or(chr),
+        _ => SYMBOLS
+            .iter()
+            .find_map(|&(full, half)| (chr == half).then_some(full))
+            .or_else(|| {
+                HALF_FORMS
+                    .iter()
+                    .find_map(|&(half, regular)| (chr == half).then_some(regular))
+            })
+            .unwrap_or(chr),
+    };
+    vec![wide]
+}
+
+/// Convert to the halfwidth form
+pub fn narrow(chr: char) -> Vec<char> {
+    if let Some(idx) = HALF_KATAKANA.iter().position(|&x| x == chr) {
+        return vec![char::from_u32(0xFF61 + idx as u32).unwrap_or(chr)];
+    }
+    if let Some(idx) = VOICED.iter().position(|&x| x == chr) {
+        return vec![HALF_VOICED[idx]];
+    }
+    if ('\u{30A0}'..='\u{30FF}').contains(&chr) {
+        // Katakana with the sound mark
+        let mut parts = Vec::new();
+        decompose_canonical(chr, |x| parts.push(x));
+        if let [base, mark] = parts[..] {
+            if let (Some(idx), Some(voice)) = (
+                HALF_KATAKANA.iter().position(|&x| x == base),
+                VOICED.iter().position(|&x| x == mark),
+            ) {
+                return vec![
+                    char::from_u32(0xFF61 + idx as u32).unwrap_or(base),
+                    HALF_VOICED[voice],
+                ];
+            }
+        }
+        return vec![chr];
+    }
+    let narrow = match chr {
+        IDEOGRAPHIC_SPACE => ' ',
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(chr as u32 - WIDE_OFFSET).unwrap_or(chr),
+        _ => SYMBOLS
+            .iter()
+            .find_map(|&(full, half)| (chr == full).then_some(half))
+            .unwrap_or(chr),
+    };
+    vec![narrow]
+}
+
+/// Convert the hiragana to the katakana
+pub fn katakana(chr: char) -> char {
+    match chr {
+        '\u{3041}'..='\u{3096}' | '\u{309D}' | '\u{309E}' => {
+            char::from_u32(chr as u32 + 0x60).unwrap_or(chr)
+        }
+        _ => chr,
+    }
+}
+
+/// Convert the katakana to the hiragana
+pub fn hiragana(chr: char) -> char {
+    match chr {
+        '\u{30A1}'..='\u{30F6}' | '\u{30FD}' | '\u{30FE}' => {
+            char::from_u32(chr as u32 - 0x60).unwrap_or(chr)
+        }
+        _ => chr,
+    }
+}
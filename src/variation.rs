@@ -0,0 +1,139 @@
+/*! Variation sequences and the variation selectors
+
+A variation selector following a character requests one of its registered glyph variants.
+The emoji variation sequences choose between the text presentation with VS15 and
+the emoji presentation with VS16, while the standardized variation sequences select the variants
+of e.g. the mathematical symbols, the CJK punctuation and the CJK compatibility ideographs.
+ */
+
+mod tables;
+#[cfg(test)]
+mod testing;
+
+use crate::codepoint::in_ranges;
+use crate::emoji::{KEYCAP, VS16};
+use unicode_normalization::char::decompose_cjk_compat_variants;
+
+pub const VS1: u32 = 0xFE00;
+pub const VS2: u32 = 0xFE01;
+/// Variation selector requesting the text presentation
+pub const VS15: u32 = 0xFE0E;
+
+/// Blocks of the CJK compatibility ideographs
+const CJK_COMPAT: [(u32, u32); 2] = [(0xF900, 0xFAD9), (0x2F800, 0x2FA1D)];
+
+/// Whether the codepoint is one of the variation selectors, including the Mongolian ones
+pub fn is_selector(cp: u32) -> bool {
+    matches!(cp, 0x180B..=0x180D | 0x180F | 0xFE00..=0xFE0F | 0xE0100..=0xE01EF)
+}
+
+fn is_presentation(cp: u32) -> bool {
+    cp == VS15 || cp == VS16
+}
+
+/// Whether the codepoint has emoji variation sequences
+pub fn has_emoji_variants(cp: u32) -> bool {
+    in_ranges(&tables::EMOJI, cp)
+}
+
+/** Whether the character at `pos` takes a presentation selector
+
+The digits, `#` and `*` only take it within a keycap, or when a selector already follows them,
+and the emoji followed by a skin tone modifier never take it.
+ */
+fn takes_presentation(buff: &[u32], pos: usize) -> bool {
+    if !has_emoji_variants(buff[pos]) {
+        return false;
+    }
+    let next = buff.get(pos + 1).copied();
+    if next.is_some_and(|x| (0x1F3FB..=0x1F3FF).contains(&x)) {
+        return false;
+    }
+    if buff[pos] < 0x80 {
+        return next.is_some_and(|x| x == KEYCAP || is_presentation(x));
+    }
+    true
+}
+
+/** Replacement of the character at `pos` when setting the presentation selectors
+
+The selector is added after each character which takes one, and the selector already
+following such a character is replaced. With no selector, every VS15 and VS16 is removed.
+ */
+pub fn presentation(buff: &[u32], pos: usize, chr: char, selector: Option<char>) -> Vec<char> {
+    if is_presentation(chr as u32) {
+        let after_base = pos > 0 && takes_presentation(buff, pos - 1);
+        return match selector {
+            Some(x) if after_base => vec![x],
+            Some(_) => vec![chr],
+            None => vec![],
+        };
+    }
+    match selector {
+        Some(x)
+            if takes_presentation(buff, pos)
+                && !buff.get(pos + 1).is_some_and(|&next| is_presentation(next)) =>
+        {
+            vec![chr, x]
+        }
+        _ => vec![chr],
+    }
+}
+
+/// Unified ideograph and selector of each compatibility ideograph equivalent to the codepoint
+fn cjk_variants(cp: u32) -> Vec<(u32, u32)> {
+    if !matches!(cp, 0x3400..=0x9FFF | 0x20000..=0x3134F) {
+        return Vec::new();
+    }
+    CJK_COMPAT
+        .iter()
+        .flat_map(|&(first, last)| first..=last)
+        .filter_map(|compat| {
+            let mut parts = Vec::new();
+            decompose_cjk_compat_variants(char::from_u32(compat)?, |x| parts.push(x as u32));
+            match parts[..] {
+                [base, selector] if base == cp => Some((selector, compat)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Standardized variation sequences of the codepoint, including the CJK compatibility ideographs
+fn standardized(cp: u32) -> Vec<(u32, String)> {
+    let mut out: Vec<(u32, String)> = tables::STANDARDIZED
+        .iter()
+        .filter(|x| x.0 == cp)
+        .map(|&(_, selector, name)| (selector, name.to_string()))
+        .collect();
+    out.extend(
+        cjk_variants(cp)
+            .into_iter()
+            .map(|(selector, compat)| (selector, format!("as U+{:04X}", compat))),
+    );
+    out
+}
+
+/** Variation sequences of the codepoint, each selector together with the description of the variant
+
+Return an empty list when the codepoint has no variation sequence.
+ */
+pub fn variants(cp: u32) -> Vec<(u32, String)> {
+    let mut out = Vec::new();
+    if has_emoji_variants(cp) {
+        out.push((VS15, "text presentation".to_string()));
+        out.push((VS16, "emoji presentation".to_string()));
+    }
+    out.extend(standardized(cp));
+    out
+}
+
+/** Positions of the buffer which have variation sequences
+
+The digits out of the keycaps are skipped, unless they have a standardized variation sequence.
+ */
+pub fn positions(buff: &[u32]) -> Vec<usize> {
+    (0..buff.len())
+        .filter(|&pos| takes_presentation(buff, pos) || !standardized(buff[pos]).is_empty())
+        .collect()
+}
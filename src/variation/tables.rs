@@ -0,0 +1,330 @@
+/*! Data of the variation sequences, as of Unicode 17.0
+ */
+
+use super::{VS1, VS2};
+
+/// Codepoints which have both a text and an emoji presentation sequence
+pub const EMOJI: [(u32, u32); 183] = [
+    (0x0023, 0x0023),
+    (0x002A, 0x002A),
+    (0x0030, 0x0039),
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21A9, 0x21AA),
+    (0x231A, 0x231B),
+    (0x2328, 0x2328),
+    (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3),
+    (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0),
+    (0x25FB, 0x25FE),
+    (0x2600, 0x2604),
+    (0x260E, 0x260E),
+    (0x2611, 0x2611),
+    (0x2614, 0x2615),
+    (0x2618, 0x2618),
+    (0x261D, 0x261D),
+    (0x2620, 0x2620),
+    (0x2622, 0x2623),
+    (0x2626, 0x2626),
+    (0x262A, 0x262A),
+    (0x262E, 0x262F),
+    (0x2638, 0x263A),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2648, 0x2653),
+    (0x265F, 0x2660),
+    (0x2663, 0x2663),
+    (0x2665, 0x2666),
+    (0x2668, 0x2668),
+    (0x267B, 0x267B),
+    (0x267E, 0x267F),
+    (0x2692, 0x2697),
+    (0x2699, 0x2699),
+    (0x269B, 0x269C),
+    (0x26A0, 0x26A1),
+    (0x26A7, 0x26A7),
+    (0x26AA, 0x26AB),
+    (0x26B0, 0x26B1),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26C8, 0x26C8),
+    (0x26CE, 0x26CF),
+    (0x26D1, 0x26D1),
+    (0x26D3, 0x26D4),
+    (0x26E9, 0x26EA),
+    (0x26F0, 0x26F5),
+    (0x26F7, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2702, 0x2702),
+    (0x2705, 0x2705),
+    (0x2708, 0x270D),
+    (0x270F, 0x270F),
+    (0x2712, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271D, 0x271D),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2764),
+    (0x2795, 0x2797),
+    (0x27A1, 0x27A1),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F004, 0x1F004),
+    (0x1F170, 0x1F171),
+    (0x1F17E, 0x1F17F),
+    (0x1F202, 0x1F202),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F237, 0x1F237),
+    (0x1F30D, 0x1F30F),
+    (0x1F315, 0x1F315),
+    (0x1F31C, 0x1F31C),
+    (0x1F321, 0x1F321),
+    (0x1F324, 0x1F32C),
+    (0x1F336, 0x1F336),
+    (0x1F378, 0x1F378),
+    (0x1F37D, 0x1F37D),
+    (0x1F393, 0x1F393),
+    (0x1F396, 0x1F397),
+    (0x1F399, 0x1F39B),
+    (0x1F39E, 0x1F39F),
+    (0x1F3A7, 0x1F3A7),
+    (0x1F3AC, 0x1F3AE),
+    (0x1F3C2, 0x1F3C2),
+    (0x1F3C4, 0x1F3C4),
+    (0x1F3C6, 0x1F3C6),
+    (0x1F3CA, 0x1F3CE),
+    (0x1F3D4, 0x1F3E0),
+    (0x1F3ED, 0x1F3ED),
+    (0x1F3F3, 0x1F3F3),
+    (0x1F3F5, 0x1F3F5),
+    (0x1F3F7, 0x1F3F7),
+    (0x1F408, 0x1F408),
+    (0x1F415, 0x1F415),
+    (0x1F41F, 0x1F41F),
+    (0x1F426, 0x1F426),
+    (0x1F43F, 0x1F43F),
+    (0x1F441, 0x1F442),
+    (0x1F446, 0x1F449),
+    (0x1F44D, 0x1F44E),
+    (0x1F453, 0x1F453),
+    (0x1F46A, 0x1F46A),
+    (0x1F47D, 0x1F47D),
+    (0x1F4A3, 0x1F4A3),
+    (0x1F4B0, 0x1F4B0),
+    (0x1F4B3, 0x1F4B3),
+    (0x1F4BB, 0x1F4BB),
+    (0x1F4BF, 0x1F4BF),
+    (0x1F4CB, 0x1F4CB),
+    (0x1F4DA, 0x1F4DA),
+    (0x1F4DF, 0x1F4DF),
+    (0x1F4E4, 0x1F4E6),
+    (0x1F4EA, 0x1F4ED),
+    (0x1F4F7, 0x1F4F7),
+    (0x1F4F9, 0x1F4FB),
+    (0x1F4FD, 0x1F4FD),
+    (0x1F508, 0x1F508),
+    (0x1F50D, 0x1F50D),
+    (0x1F512, 0x1F513),
+    (0x1F549, 0x1F54A),
+    (0x1F550, 0x1F567),
+    (0x1F56F, 0x1F570),
+    (0x1F573, 0x1F579),
+    (0x1F587, 0x1F587),
+    (0x1F58A, 0x1F58D),
+    (0x1F590, 0x1F590),
+    (0x1F5A5, 0x1F5A5),
+    (0x1F5A8, 0x1F5A8),
+    (0x1F5B1, 0x1F5B2),
+    (0x1F5BC, 0x1F5BC),
+    (0x1F5C2, 0x1F5C4),
+    (0x1F5D1, 0x1F5D3),
+    (0x1F5DC, 0x1F5DE),
+    (0x1F5E1, 0x1F5E1),
+    (0x1F5E3, 0x1F5E3),
+    (0x1F5E8, 0x1F5E8),
+    (0x1F5EF, 0x1F5EF),
+    (0x1F5F3, 0x1F5F3),
+    (0x1F5FA, 0x1F5FA),
+    (0x1F610, 0x1F610),
+    (0x1F687, 0x1F687),
+    (0x1F68D, 0x1F68D),
+    (0x1F691, 0x1F691),
+    (0x1F694, 0x1F694),
+    (0x1F698, 0x1F698),
+    (0x1F6AD, 0x1F6AD),
+    (0x1F6B2, 0x1F6B2),
+    (0x1F6B9, 0x1F6BA),
+    (0x1F6BC, 0x1F6BC),
+    (0x1F6CB, 0x1F6CB),
+    (0x1F6CD, 0x1F6CF),
+    (0x1F6E0, 0x1F6E5),
+    (0x1F6E9, 0x1F6E9),
+    (0x1F6F0, 0x1F6F0),
+    (0x1F6F3, 0x1F6F3),
+];
+
+/** Standardized variation sequences, with the description of the variant selected by each selector
+
+Sorted by codepoint, a character may have several rows, one per selector. The rows of
+`StandardizedVariants.txt` are listed for the digit zero, the mathematical symbols and script
+capitals, the Myanmar letters and the CJK punctuation. The Phags-pa, Manichaean, Mongolian
+and Egyptian hieroglyph rows are not listed yet, and the CJK compatibility ideographs are
+derived from their decomposition instead.
+ */
+pub const STANDARDIZED: [(u32, u32, &str); 120] = [
+    (0x0030, VS1, "short diagonal stroke form"),
+    (0x1000, VS1, "dotted form"),
+    (0x1002, VS1, "dotted form"),
+    (0x1004, VS1, "dotted form"),
+    (0x1010, VS1, "dotted form"),
+    (0x1011, VS1, "dotted form"),
+    (0x1015, VS1, "dotted form"),
+    (0x1019, VS1, "dotted form"),
+    (0x101A, VS1, "dotted form"),
+    (0x101C, VS1, "dotted form"),
+    (0x101D, VS1, "dotted form"),
+    (0x1022, VS1, "dotted form"),
+    (0x1031, VS1, "dotted form"),
+    (0x1075, VS1, "dotted form"),
+    (0x1078, VS1, "dotted form"),
+    (0x107A, VS1, "dotted form"),
+    (0x1080, VS1, "dotted form"),
+    (0x210B, VS1, "chancery style"),
+    (0x210B, VS2, "roundhand style"),
+    (0x2110, VS1, "chancery style"),
+    (0x2110, VS2, "roundhand style"),
+    (0x2112, VS1, "chancery style"),
+    (0x2112, VS2, "roundhand style"),
+    (0x211B, VS1, "chancery style"),
+    (0x211B, VS2, "roundhand style"),
+    (0x212C, VS1, "chancery style"),
+    (0x212C, VS2, "roundhand style"),
+    (0x2130, VS1, "chancery style"),
+    (0x2130, VS2, "roundhand style"),
+    (0x2131, VS1, "chancery style"),
+    (0x2131, VS2, "roundhand style"),
+    (0x2133, VS1, "chancery style"),
+    (0x2133, VS2, "roundhand style"),
+    (0x2205, VS1, "zero with long diagonal stroke overlay form"),
+    (0x2229, VS1, "with serifs"),
+    (0x222A, VS1, "with serifs"),
+    (0x2268, VS1, "with vertical stroke"),
+    (0x2269, VS1, "with vertical stroke"),
+    (0x2272, VS1, "following the slant of the lower leg"),
+    (0x2273, VS1, "following the slant of the lower leg"),
+    (0x228A, VS1, "with vertical stroke"),
+    (0x228B, VS1, "with vertical stroke"),
+    (0x2293, VS1, "with serifs"),
+    (0x2294, VS1, "with serifs"),
+    (0x2295, VS1, "with white rim"),
+    (0x2297, VS1, "with white rim"),
+    (0x229C, VS1, "with equal sign touching the circle"),
+    (0x22DA, VS1, "with slanted equal"),
+    (0x22DB, VS1, "with slanted equal"),
+    (0x2A3C, VS1, "tall variant with narrow foot"),
+    (0x2A3D, VS1, "tall variant with narrow foot"),
+    (
+        0x2A9D,
+        VS1,
+        "with similar following the slant of the upper leg",
+    ),
+    (
+        0x2A9E,
+        VS1,
+        "with similar following the slant of the upper leg",
+    ),
+    (0x2AAC, VS1, "with slanted equal"),
+    (0x2AAD, VS1, "with slanted equal"),
+    (0x2ACB, VS1, "with vertical stroke"),
+    (0x2ACC, VS1, "with vertical stroke"),
+    (0x3001, VS1, "corner-justified form"),
+    (0x3001, VS2, "centered form"),
+    (0x3002, VS1, "corner-justified form"),
+    (0x3002, VS2, "centered form"),
+    (0xAA60, VS1, "dotted form"),
+    (0xAA61, VS1, "dotted form"),
+    (0xAA62, VS1, "dotted form"),
+    (0xAA63, VS1, "dotted form"),
+    (0xAA64, VS1, "dotted form"),
+    (0xAA65, VS1, "dotted form"),
+    (0xAA66, VS1, "dotted form"),
+    (0xAA6B, VS1, "dotted form"),
+    (0xAA6C, VS1, "dotted form"),
+    (0xAA6F, VS1, "dotted form"),
+    (0xAA7A, VS1, "dotted form"),
+    (0xFF01, VS1, "corner-justified form"),
+    (0xFF01, VS2, "centered form"),
+    (0xFF0C, VS1, "corner-justified form"),
+    (0xFF0C, VS2, "centered form"),
+    (0xFF0E, VS1, "corner-justified form"),
+    (0xFF0E, VS2, "centered form"),
+    (0xFF1A, VS1, "corner-justified form"),
+    (0xFF1A, VS2, "centered form"),
+    (0xFF1B, VS1, "corner-justified form"),
+    (0xFF1B, VS2, "centered form"),
+    (0xFF1F, VS1, "corner-justified form"),
+    (0xFF1F, VS2, "centered form"),
+    (0x1D49C, VS1, "chancery style"),
+    (0x1D49C, VS2, "roundhand style"),
+    (0x1D49E, VS1, "chancery style"),
+    (0x1D49E, VS2, "roundhand style"),
+    (0x1D49F, VS1, "chancery style"),
+    (0x1D49F, VS2, "roundhand style"),
+    (0x1D4A2, VS1, "chancery style"),
+    (0x1D4A2, VS2, "roundhand style"),
+    (0x1D4A5, VS1, "chancery style"),
+    (0x1D4A5, VS2, "roundhand style"),
+    (0x1D4A6, VS1, "chancery style"),
+    (0x1D4A6, VS2, "roundhand style"),
+    (0x1D4A9, VS1, "chancery style"),
+    (0x1D4A9, VS2, "roundhand style"),
+    (0x1D4AA, VS1, "chancery style"),
+    (0x1D4AA, VS2, "roundhand style"),
+    (0x1D4AB, VS1, "chancery style"),
+    (0x1D4AB, VS2, "roundhand style"),
+    (0x1D4AC, VS1, "chancery style"),
+    (0x1D4AC, VS2, "roundhand style"),
+    (0x1D4AE, VS1, "chancery style"),
+    (0x1D4AE, VS2, "roundhand style"),
+    (0x1D4AF, VS1, "chancery style"),
+    (0x1D4AF, VS2, "roundhand style"),
+    (0x1D4B0, VS1, "chancery style"),
+    (0x1D4B0, VS2, "roundhand style"),
+    (0x1D4B1, VS1, "chancery style"),
+    (0x1D4B1, VS2, "roundhand style"),
+    (0x1D4B2, VS1, "chancery style"),
+    (0x1D4B2, VS2, "roundhand style"),
+    (0x1D4B3, VS1, "chancery style"),
+    (0x1D4B3, VS2, "roundhand style"),
+    (0x1D4B4, VS1, "chancery style"),
+    (0x1D4B4, VS2, "roundhand style"),
+    (0x1D4B5, VS1, "chancery style"),
+    (0x1D4B5, VS2, "roundhand style"),
+];
@@ -0,0 +1,73 @@
+/*! Testing submodule for the variation module
+ */
+
+use super::*;
+
+#[test]
+fn selectors() {
+    assert!(is_selector(0xFE0F));
+    assert!(is_selector(0xE0100));
+    assert!(is_selector(0x180B));
+    assert!(!is_selector(0x200D));
+}
+
+#[test]
+fn emoji_variants() {
+    assert_eq!(
+        variants(0x2764),
+        vec![
+            (VS15, "text presentation".to_string()),
+            (VS16, "emoji presentation".to_string())
+        ]
+    );
+    assert!(has_emoji_variants(0x231A));
+    assert!(!has_emoji_variants(0x1F600));
+}
+
+#[test]
+fn standardized_variants() {
+    assert_eq!(variants(0x2229), vec![(VS1, "with serifs".to_string())]);
+    assert_eq!(
+        variants('0' as u32),
+        vec![
+            (VS15, "text presentation".to_string()),
+            (VS16, "emoji presentation".to_string()),
+            (VS1, "short diagonal stroke form".to_string())
+        ]
+    );
+    // 漢 has two compatibility ideographs, U+FA47 and U+FA9A
+    assert_eq!(
+        variants(0x6F22),
+        vec![
+            (VS1, "as U+FA47".to_string()),
+            (VS2, "as U+FA9A".to_string())
+        ]
+    );
+    // The ideographic comma has both the corner-justified and the centered form
+    assert_eq!(
+        variants(0x3001),
+        vec![
+            (VS1, "corner-justified form".to_string()),
+            (VS2, "centered form".to_string())
+        ]
+    );
+    assert_eq!(
+        variants(0x212C),
+        vec![
+            (VS1, "chancery style".to_string()),
+            (VS2, "roundhand style".to_string())
+        ]
+    );
+    assert_eq!(variants(0x1000), vec![(VS1, "dotted form".to_string())]);
+    assert_eq!(variants('a' as u32), vec![]);
+}
+
+#[test]
+fn listed_positions() {
+    // The plain digit is skipped, the digit of the keycap is listed
+    let buff = [0x31, 0x32, 0xFE0F, 0x20E3, 0x2764, 0x1F44D, 0x1F3FD, 0x6F22];
+    assert_eq!(positions(&buff), vec![1, 4, 7]);
+    // The plain zero has a standardized variation sequence
+    assert_eq!(positions(&[0x30, 0x31]), vec![0]);
+    assert_eq!(positions(&[0x61, 0x3002, 0xFF0C]), vec![1, 2]);
+}